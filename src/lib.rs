@@ -1,23 +1,69 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 pub mod time_utils {
-    use chrono::{DateTime, FixedOffset, NaiveDateTime, Utc};
-    use serde::{Deserialize, Serialize};
-    use std::{
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
+    #[cfg(feature = "std")]
+    use chrono::{
+        DateTime, Datelike, FixedOffset, Local, NaiveDate, NaiveDateTime, Timelike, Utc, Weekday,
+    };
+    use core::{
         cmp::{Ordering, PartialOrd},
         fmt,
+        iter::Sum,
         ops::{Add, Div, Mul, Sub},
+        time::Duration,
+    };
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+    #[cfg(feature = "std")]
+    use std::{
+        collections::HashMap,
         str::FromStr,
-        time::{Duration, SystemTime},
+        time::{Instant, SystemTime},
     };
 
     // Custom Error Type
-    #[derive(Debug, Clone)]
+    /// Errors returned by the time and duration APIs.
+    ///
+    /// Errors can be compared directly, including the messages they carry.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time_duration_api::time_utils::{Time, TimeError};
+    /// assert_eq!(TimeError::InvalidTime, TimeError::InvalidTime);
+    /// assert_eq!(
+    ///     TimeError::ParseError("bad".to_string()),
+    ///     TimeError::ParseError("bad".to_string())
+    /// );
+    /// assert_ne!(
+    ///     TimeError::ParseError("bad".to_string()),
+    ///     TimeError::ParseError("worse".to_string())
+    /// );
+    /// assert_ne!(TimeError::InvalidTime, TimeError::InvalidTimeFormat("bad".to_string()));
+    /// let mut time = Time::now();
+    /// assert_eq!(
+    ///     time.to_timezone("nowhere"),
+    ///     Err(TimeError::InvalidTimezoneFormat("nowhere".to_string()))
+    /// );
+    /// ```
+    #[derive(Debug, Clone, PartialEq, Eq)]
     pub enum TimeError {
         InvalidTime,
         InvalidTimeFormat(String),
         InvalidTimezoneFormat(String),
         ParseError(String), // Generic parsing error
+        OutOfRange(String),
     }
 
+    #[cfg(feature = "std")]
     impl std::error::Error for TimeError {}
 
     impl fmt::Display for TimeError {
@@ -29,20 +75,193 @@ pub mod time_utils {
                     write!(f, "Invalid timezone format: {}", msg)
                 }
                 TimeError::ParseError(msg) => write!(f, "Parse error: {}", msg),
+                TimeError::OutOfRange(msg) => write!(f, "Out of range: {}", msg),
             }
         }
     }
 
     // Custom Result Type
-    pub type Result<T> = std::result::Result<T, TimeError>;
+    pub type Result<T> = core::result::Result<T, TimeError>;
+
+    // How a format in the flexible parsing catalog is interpreted
+    #[cfg(feature = "std")]
+    #[derive(Clone, Copy)]
+    enum FormatKind {
+        Rfc3339,
+        Rfc2822,
+        WithOffset,
+        Naive,
+        DateOnly,
+    }
 
-    #[derive(Debug, Clone, Serialize, Deserialize)]
+    // Formats tried in order by `Time::parse_flexible`; formats without an offset are read as UTC
+    #[cfg(feature = "std")]
+    const FLEXIBLE_FORMATS: [(&str, FormatKind); 10] = [
+        ("%+", FormatKind::Rfc3339),
+        ("%a, %d %b %Y %H:%M:%S %z", FormatKind::Rfc2822),
+        ("%Y-%m-%d %H:%M:%S%.f%z", FormatKind::WithOffset),
+        ("%Y-%m-%d %H:%M:%S%.f", FormatKind::Naive),
+        ("%Y-%m-%dT%H:%M:%S%.f", FormatKind::Naive),
+        ("%Y/%m/%d %H:%M:%S", FormatKind::Naive),
+        ("%Y-%m-%d %H:%M", FormatKind::Naive),
+        ("%Y-%m-%d", FormatKind::DateOnly),
+        ("%Y/%m/%d", FormatKind::DateOnly),
+        ("%d %b %Y", FormatKind::DateOnly),
+    ];
+
+    // The Julian Date of the Unix epoch, and the length of a day in nanoseconds
+    #[cfg(feature = "std")]
+    const UNIX_EPOCH_JULIAN_DAY: f64 = 2_440_587.5;
+    #[cfg(feature = "std")]
+    const NANOS_PER_DAY: f64 = 86_400_000_000_000.0;
+
+    #[cfg(feature = "std")]
+    #[derive(Debug, Clone)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
     pub struct Time {
         timestamp: SystemTime,
-        #[serde(skip)]
+        #[cfg_attr(feature = "serde", serde(skip))]
         cached_utc_datetime: Option<DateTime<Utc>>, // Cache the Utc DateTime
     }
 
+    /// A calendar unit of time, used by `Time::truncate_to`.
+    #[cfg(feature = "std")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum TimeUnit {
+        Second,
+        Minute,
+        Hour,
+        Day,
+    }
+
+    /// Common representations of a time, as returned by `Time::format_all`.
+    #[cfg(feature = "std")]
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize))]
+    pub struct TimeRepr {
+        /// RFC 3339 in UTC, e.g. "2023-10-27T12:00:00+00:00".
+        pub rfc3339: String,
+        /// RFC 2822 in UTC, e.g. "Fri, 27 Oct 2023 12:00:00 +0000".
+        pub rfc2822: String,
+        /// Seconds since the Unix epoch, negative before it.
+        pub unix_secs: i64,
+        /// Milliseconds since the Unix epoch, negative before it.
+        pub unix_millis: i64,
+        /// The ISO 8601 week, e.g. "2023-W43".
+        pub iso_week: String,
+    }
+
+    /// A Unix timestamp in whole seconds, as returned by `Time::unix_seconds`.
+    #[cfg(feature = "std")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct Seconds(pub u64);
+
+    #[cfg(feature = "std")]
+    impl Seconds {
+        /// Returns the number of seconds.
+        pub fn get(self) -> u64 {
+            self.0
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl fmt::Display for Seconds {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}s", self.0)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl From<Seconds> for u64 {
+        fn from(seconds: Seconds) -> Self {
+            seconds.0
+        }
+    }
+
+    /// A Unix timestamp in whole milliseconds, as returned by `Time::unix_millis`.
+    #[cfg(feature = "std")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct Millis(pub u128);
+
+    #[cfg(feature = "std")]
+    impl Millis {
+        /// Returns the number of milliseconds.
+        pub fn get(self) -> u128 {
+            self.0
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl fmt::Display for Millis {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}ms", self.0)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl From<Millis> for u128 {
+        fn from(millis: Millis) -> Self {
+            millis.0
+        }
+    }
+
+    /// A coarse classification of how long ago a time was, as returned by `Time::age_bucket`.
+    ///
+    /// Each bucket covers ages from the previous bucket's limit up to, but not including,
+    /// its own limit constant.
+    #[cfg(feature = "std")]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum AgeBucket {
+        JustNow,
+        Minutes,
+        Hours,
+        Days,
+        Weeks,
+        Older,
+    }
+
+    #[cfg(feature = "std")]
+    impl AgeBucket {
+        /// Ages below this many seconds (one minute) are `JustNow`.
+        pub const JUST_NOW_LIMIT_SECS: u64 = 60;
+        /// Ages below this many seconds (one hour) are `Minutes`.
+        pub const MINUTES_LIMIT_SECS: u64 = 3600;
+        /// Ages below this many seconds (one day) are `Hours`.
+        pub const HOURS_LIMIT_SECS: u64 = 86400;
+        /// Ages below this many seconds (one week) are `Days`.
+        pub const DAYS_LIMIT_SECS: u64 = 7 * 86400;
+        /// Ages below this many seconds (four weeks) are `Weeks`, and older ones are `Older`.
+        pub const WEEKS_LIMIT_SECS: u64 = 28 * 86400;
+
+        /// Returns the bucket for an age.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{AgeBucket, CustomDuration};
+        /// let secs = CustomDuration::from_secs;
+        /// assert_eq!(AgeBucket::from_age(&secs(59)), AgeBucket::JustNow);
+        /// assert_eq!(AgeBucket::from_age(&secs(AgeBucket::JUST_NOW_LIMIT_SECS)), AgeBucket::Minutes);
+        /// assert_eq!(AgeBucket::from_age(&secs(AgeBucket::WEEKS_LIMIT_SECS - 1)), AgeBucket::Weeks);
+        /// assert_eq!(AgeBucket::from_age(&secs(AgeBucket::WEEKS_LIMIT_SECS)), AgeBucket::Older);
+        /// ```
+        pub fn from_age(age: &CustomDuration) -> AgeBucket {
+            match age.as_secs() {
+                secs if secs < AgeBucket::JUST_NOW_LIMIT_SECS => AgeBucket::JustNow,
+                secs if secs < AgeBucket::MINUTES_LIMIT_SECS => AgeBucket::Minutes,
+                secs if secs < AgeBucket::HOURS_LIMIT_SECS => AgeBucket::Hours,
+                secs if secs < AgeBucket::DAYS_LIMIT_SECS => AgeBucket::Days,
+                secs if secs < AgeBucket::WEEKS_LIMIT_SECS => AgeBucket::Weeks,
+                _ => AgeBucket::Older,
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
     impl Time {
         /// Creates a new Time instance with the current system time.
         ///
@@ -54,12 +273,57 @@ pub mod time_utils {
         /// println!("Current time: {}", now.format("%Y-%m-%d %H:%M:%S").unwrap());
         /// ```
         pub fn now() -> Self {
+            Time::now_with(&SystemClock)
+        }
+
+        /// Creates a new Time instance with the current time of the given clock.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{FixedClock, Time};
+        /// let fixed = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let now = Time::now_with(&FixedClock::new(&fixed));
+        /// assert_eq!(now.as_system_time(), fixed.as_system_time());
+        /// ```
+        pub fn now_with(clock: &dyn Clock) -> Self {
+            Time {
+                timestamp: clock.now(),
+                cached_utc_datetime: None,
+            }
+        }
+
+        /// Creates a Time instance from a `SystemTime`, e.g. a file's modification time.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use std::time::{Duration, SystemTime};
+        /// use time_duration_api::time_utils::Time;
+        /// let system_time = SystemTime::UNIX_EPOCH + Duration::from_millis(1698408000123);
+        /// let time = Time::from_system_time(system_time);
+        /// assert_eq!(time.as_system_time(), system_time);
+        /// ```
+        pub fn from_system_time(timestamp: SystemTime) -> Self {
             Time {
-                timestamp: SystemTime::now(),
+                timestamp,
                 cached_utc_datetime: None,
             }
         }
 
+        /// Returns the underlying `SystemTime`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let modified = std::fs::metadata("Cargo.toml").unwrap().modified().unwrap();
+        /// assert_eq!(Time::from(modified).as_system_time(), modified);
+        /// ```
+        pub fn as_system_time(&self) -> SystemTime {
+            self.timestamp
+        }
+
         /// Formats the time with the given format string.
         ///
         /// Returns a formatted time string or an error if time is invalid.
@@ -83,22 +347,31 @@ pub mod time_utils {
                 return Ok(cached);
             }
 
+            let datetime = self.utc_datetime()?;
+
+            self.cached_utc_datetime = Some(datetime); // Cache the DateTime
+            Ok(datetime)
+        }
+
+        // Helper function to get the cached DateTime<Utc> or generate it without caching
+        fn utc_datetime(&self) -> Result<DateTime<Utc>> {
+            if let Some(cached) = self.cached_utc_datetime {
+                return Ok(cached);
+            }
+
             let duration = self
                 .timestamp
                 .duration_since(SystemTime::UNIX_EPOCH)
                 .map_err(|_| TimeError::InvalidTime)?;
 
-            let datetime = DateTime::<Utc>::from_naive_utc_and_offset(
+            Ok(DateTime::<Utc>::from_naive_utc_and_offset(
                 NaiveDateTime::from_timestamp_opt(
                     duration.as_secs() as i64,
                     duration.subsec_nanos(),
                 )
                 .ok_or(TimeError::InvalidTime)?,
                 Utc,
-            );
-
-            self.cached_utc_datetime = Some(datetime); // Cache the DateTime
-            Ok(datetime)
+            ))
         }
         /// Formats the time with a given format string and timezone.
         ///
@@ -114,9 +387,7 @@ pub mod time_utils {
         /// ```
         pub fn format_with_timezone(&mut self, format: &str, timezone: &str) -> Result<String> {
             let datetime = self.get_utc_datetime()?;
-            let tz: FixedOffset = timezone
-                .parse()
-                .map_err(|_| TimeError::InvalidTimezoneFormat(timezone.to_string()))?;
+            let tz = parse_fixed_offset(timezone)?;
             Ok(datetime.with_timezone(&tz).format(format).to_string())
         }
 
@@ -139,6 +410,78 @@ pub mod time_utils {
                 .map_err(|_| TimeError::InvalidTime)
         }
 
+        /// Gets the timestamp in milliseconds.
+        ///
+        /// Returns the timestamp as a u128 or an error if time is invalid.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:00:00.250+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+        /// assert_eq!(time.timestamp_millis().unwrap(), 1698408000250);
+        /// ```
+        pub fn timestamp_millis(&self) -> Result<u128> {
+            self.timestamp
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map(|duration| duration.as_millis())
+                .map_err(|_| TimeError::InvalidTime)
+        }
+
+        /// Gets the timestamp as whole `Seconds`, so it cannot be mixed up with milliseconds.
+        ///
+        /// Returns an error if the time is before the Unix epoch.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{Seconds, Time};
+        /// let time = Time::from_str("2023-10-27 12:00:00.250+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+        /// let secs = time.unix_seconds().unwrap();
+        /// assert_eq!(secs, Seconds(1698408000));
+        /// assert_eq!(secs.get(), 1698408000);
+        /// assert_eq!(secs.to_string(), "1698408000s");
+        /// ```
+        pub fn unix_seconds(&self) -> Result<Seconds> {
+            self.timestamp().map(Seconds)
+        }
+
+        /// Gets the timestamp as whole `Millis`, so it cannot be mixed up with seconds.
+        ///
+        /// Returns an error if the time is before the Unix epoch.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{Millis, Time};
+        /// let time = Time::from_str("2023-10-27 12:00:00.250+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+        /// let millis = time.unix_millis().unwrap();
+        /// assert_eq!(millis, Millis(1698408000250));
+        /// assert_eq!(u128::from(millis), 1698408000250);
+        /// assert_eq!(millis.to_string(), "1698408000250ms");
+        /// ```
+        pub fn unix_millis(&self) -> Result<Millis> {
+            self.timestamp_millis().map(Millis)
+        }
+
+        /// Gets the timestamp in nanoseconds.
+        ///
+        /// Returns the timestamp as a u128 or an error if time is invalid.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:00:00.123456789+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+        /// assert_eq!(time.timestamp_nanos().unwrap(), 1698408000123456789);
+        /// ```
+        pub fn timestamp_nanos(&self) -> Result<u128> {
+            self.timestamp
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map(|duration| duration.as_nanos())
+                .map_err(|_| TimeError::InvalidTime)
+        }
+
         /// Adds a custom duration to the current time.
         ///
         /// # Example
@@ -189,12 +532,47 @@ pub mod time_utils {
         /// ```
         pub fn to_timezone(&mut self, timezone: &str) -> Result<String> {
             let datetime = self.get_utc_datetime()?;
-            let tz: FixedOffset = timezone
-                .parse()
-                .map_err(|_| TimeError::InvalidTimezoneFormat(timezone.to_string()))?;
+            let tz = parse_fixed_offset(timezone)?;
             Ok(datetime.with_timezone(&tz).to_string())
         }
 
+        /// Converts the time to the system's local timezone.
+        ///
+        /// The local timezone is read from the environment (e.g. the `TZ` variable
+        /// or the OS settings), so the result depends on the machine it runs on.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let mut time = Time::now();
+        /// let local_time = time.to_local().unwrap();
+        /// println!("Local time: {}", local_time);
+        /// ```
+        pub fn to_local(&mut self) -> Result<String> {
+            Ok(self.to_local_datetime()?.to_string())
+        }
+
+        /// Converts the time to a `DateTime` in the system's local timezone.
+        ///
+        /// Like `to_local`, the result depends on the timezone configured in the
+        /// environment.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{DateTime, Local};
+        /// use std::time::SystemTime;
+        /// use time_duration_api::time_utils::Time;
+        /// let mut time = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let instant = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1698408000);
+        /// assert_eq!(time.to_local_datetime().unwrap(), DateTime::<Local>::from(instant));
+        /// ```
+        pub fn to_local_datetime(&mut self) -> Result<DateTime<Local>> {
+            let datetime = self.get_utc_datetime()?;
+            Ok(datetime.with_timezone(&Local))
+        }
+
         /// Creates a Time instance from a formatted time string.
         ///
         /// Returns the time or an error if the format is invalid.
@@ -218,257 +596,3472 @@ pub mod time_utils {
                 ))),
             }
         }
-    }
 
-    impl fmt::Display for Time {
-        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            match self.timestamp.duration_since(SystemTime::UNIX_EPOCH) {
-                Ok(duration) => {
-                    let datetime = DateTime::<Utc>::from_naive_utc_and_offset(
-                        NaiveDateTime::from_timestamp_opt(
-                            duration.as_secs() as i64,
-                            duration.subsec_nanos(),
-                        )
-                        .unwrap(),
-                        Utc,
-                    );
-                    write!(f, "{}", datetime.format("%Y-%m-%d %H:%M:%S"))
-                }
-                Err(_) => write!(f, "Invalid Time"),
+        /// Creates a Time instance from a formatted time string without an offset,
+        /// treating it as UTC.
+        ///
+        /// Returns the time or an error if the format is invalid.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str_assume_utc("2023-10-27 12:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        /// assert_eq!(time.timestamp().unwrap(), 1698408000);
+        /// ```
+        pub fn from_str_assume_utc(time_str: &str, format: &str) -> Result<Self> {
+            match NaiveDateTime::parse_from_str(time_str, format) {
+                Ok(naive) => Ok(Time {
+                    timestamp: SystemTime::from(DateTime::<Utc>::from_naive_utc_and_offset(
+                        naive, Utc,
+                    )),
+                    cached_utc_datetime: None,
+                }),
+                Err(e) => Err(TimeError::InvalidTimeFormat(format!(
+                    "Failed to parse '{}' with format '{}': {}",
+                    time_str, format, e
+                ))),
             }
         }
-    }
-
-    #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
-    pub struct CustomDuration {
-        duration: Duration,
-    }
 
-    impl CustomDuration {
-        /// Creates a CustomDuration from a number of seconds.
+        /// Creates a Time instance from a formatted time string without an offset,
+        /// interpreting it at the given UTC offset (e.g. "+05:30").
+        ///
+        /// Returns an `InvalidTimezoneFormat` error for a bad offset, or an
+        /// `InvalidTimeFormat` error if the string does not match the format.
         ///
         /// # Example
         ///
         /// ```
-        /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_secs(60);
-        /// println!("Duration: {}", duration.format_human_readable());
+        /// use time_duration_api::time_utils::Time;
+        /// let format = "%Y-%m-%d %H:%M:%S";
+        /// let utc = Time::parse_with_timezone("2023-10-27 12:00:00", format, "+00:00").unwrap();
+        /// let ist = Time::parse_with_timezone("2023-10-27 12:00:00", format, "+05:30").unwrap();
+        /// assert_eq!(utc.timestamp().unwrap(), 1698408000);
+        /// assert_eq!(utc.timestamp().unwrap() - ist.timestamp().unwrap(), 19800);
+        /// assert!(Time::parse_with_timezone("2023-10-27 12:00:00", format, "IST").is_err());
+        /// assert!(Time::parse_with_timezone("yesterday", format, "+05:30").is_err());
         /// ```
-        pub fn from_secs(secs: u64) -> Self {
-            CustomDuration {
-                duration: Duration::from_secs(secs),
-            }
+        pub fn parse_with_timezone(time_str: &str, format: &str, offset: &str) -> Result<Self> {
+            let offset = parse_fixed_offset(offset)?;
+            let naive = NaiveDateTime::parse_from_str(time_str, format).map_err(|e| {
+                TimeError::InvalidTimeFormat(format!(
+                    "Failed to parse '{}' with format '{}': {}",
+                    time_str, format, e
+                ))
+            })?;
+            naive
+                .and_local_timezone(offset)
+                .single()
+                .map(|datetime| Time::from(datetime.to_utc()))
+                .ok_or(TimeError::InvalidTime)
         }
 
-        /// Creates a CustomDuration from a number of milliseconds.
+        /// Formats the time in an IANA timezone (e.g. "America/Los_Angeles"), rendering
+        /// `%Z` as the zone abbreviation in effect at that instant (e.g. "PDT" or "PST").
+        ///
+        /// Returns a formatted time string or an error if time or timezone is invalid.
+        /// Requires the `tz` feature.
         ///
         /// # Example
         ///
         /// ```
-        /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_millis(1000);
-        /// println!("Duration: {}", duration.format_human_readable());
+        /// use time_duration_api::time_utils::Time;
+        /// let summer = Time::from_str("2023-07-01 19:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let winter = Time::from_str("2023-01-01 20:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(
+        ///     summer.format_with_zone_name("%H:%M:%S %Z", "America/Los_Angeles").unwrap(),
+        ///     "12:00:00 PDT"
+        /// );
+        /// assert_eq!(
+        ///     winter.format_with_zone_name("%H:%M:%S %Z", "America/Los_Angeles").unwrap(),
+        ///     "12:00:00 PST"
+        /// );
         /// ```
-        pub fn from_millis(millis: u64) -> Self {
-            CustomDuration {
-                duration: Duration::from_millis(millis),
-            }
+        #[cfg(feature = "tz")]
+        pub fn format_with_zone_name(&self, format: &str, timezone: &str) -> Result<String> {
+            let datetime = self.utc_datetime()?;
+            let tz: chrono_tz::Tz = timezone
+                .parse()
+                .map_err(|_| TimeError::InvalidTimezoneFormat(timezone.to_string()))?;
+            Ok(datetime.with_timezone(&tz).format(format).to_string())
         }
 
-        /// Creates a CustomDuration from a number of microseconds.
+        /// Counts the weekdays (Monday to Friday) strictly between this time and `other`.
+        ///
+        /// Both dates are taken in UTC and neither endpoint's day is counted. The result is
+        /// positive if `other` is later than this time and negative if it is earlier.
         ///
         /// # Example
         ///
         /// ```
-        /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_micros(1000000);
-        /// println!("Duration: {}", duration.format_human_readable());
+        /// use time_duration_api::time_utils::Time;
+        /// let thursday = Time::from_str("2023-10-26 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let next_tuesday = Time::from_str("2023-10-31 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let tuesday_after = Time::from_str("2023-11-07 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(thursday.business_days_between(&next_tuesday).unwrap(), 2);
+        /// assert_eq!(thursday.business_days_between(&tuesday_after).unwrap(), 7);
+        /// assert_eq!(next_tuesday.business_days_between(&thursday).unwrap(), -2);
+        /// assert_eq!(thursday.business_days_between(&thursday).unwrap(), 0);
+        ///
+        /// // The sign follows `other - self`: calling it on the later time is negative.
+        /// let (earlier, later) = (&thursday, &tuesday_after);
+        /// assert!(earlier.business_days_between(later).unwrap() > 0);
+        /// assert!(later.business_days_between(earlier).unwrap() < 0);
         /// ```
-        pub fn from_micros(micros: u64) -> Self {
-            CustomDuration {
-                duration: Duration::from_micros(micros),
+        pub fn business_days_between(&self, other: &Time) -> Result<i64> {
+            let this = self.signed_utc_datetime()?.date_naive();
+            let other = other.signed_utc_datetime()?.date_naive();
+            if this <= other {
+                Ok(Time::count_business_days(this, other) as i64)
+            } else {
+                Ok(-(Time::count_business_days(other, this) as i64))
             }
         }
 
-        /// Creates a CustomDuration from a number of nanoseconds.
+        // Helper function to count the weekdays strictly between two dates
+        fn count_business_days(start: NaiveDate, end: NaiveDate) -> u32 {
+            start
+                .iter_days()
+                .skip(1)
+                .take_while(|date| *date < end)
+                .filter(|date| !matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+                .count() as u32
+        }
+
+        /// Moves the time forward by a number of business days, keeping the time of day.
+        ///
+        /// Saturdays and Sundays (in UTC) are skipped. A negative `days` moves backward.
+        /// Returns an error if the result is out of range.
         ///
         /// # Example
         ///
         /// ```
-        /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_nanos(1000000000);
-        /// println!("Duration: {}", duration.format_human_readable());
+        /// use chrono::{DateTime, Utc};
+        /// use time_duration_api::time_utils::Time;
+        /// let friday = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let tuesday = friday.add_business_days(2).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(tuesday.clone()).unwrap().format("%Y-%m-%d %H:%M").to_string(), "2023-10-31 12:00");
+        /// let thursday = friday.add_business_days(-1).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(thursday.clone()).unwrap().format("%Y-%m-%d").to_string(), "2023-10-26");
         /// ```
-        pub fn from_nanos(nanos: u64) -> Self {
-            CustomDuration {
-                duration: Duration::from_nanos(nanos),
-            }
+        pub fn add_business_days(&self, days: i64) -> Result<Time> {
+            self.add_business_days_with(days, |_| false)
         }
 
-        /// Creates a CustomDuration from a human-readable string (e.g., "1h 30m").
+        /// Moves the time forward by a number of business days, skipping weekends and holidays.
         ///
-        /// Returns the duration or an error if the string is invalid.
+        /// A day counts as a business day if it is not a Saturday or Sunday (in UTC) and
+        /// `is_holiday` returns false for it. The predicate is called with each candidate
+        /// day at this time's time of day. A negative `days` moves backward. Returns an
+        /// error if the result is out of range.
         ///
         /// # Example
         ///
         /// ```
-        /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_str("2h 30m").unwrap();
-        /// println!("Duration: {}", duration.format_human_readable());
+        /// use chrono::{DateTime, Utc};
+        /// use time_duration_api::time_utils::Time;
+        /// let monday = Time::from_str("2023-12-18 09:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let is_holiday = |day: &Time| DateTime::<Utc>::try_from(day.clone()).unwrap().format("%m-%d").to_string() == "12-20";
+        /// let result = monday.add_business_days_with(3, is_holiday).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(result.clone()).unwrap().format("%Y-%m-%d %H:%M").to_string(), "2023-12-22 09:00");
+        /// let back = result.add_business_days_with(-3, is_holiday).unwrap();
+        /// assert_eq!(back.as_system_time(), monday.as_system_time());
         /// ```
-        pub fn from_str(duration_str: &str) -> Result<Self> {
-            humantime::parse_duration(duration_str)
-                .map(|dur| CustomDuration { duration: dur })
-                .map_err(|e| TimeError::ParseError(e.to_string()))
+        pub fn add_business_days_with(
+            &self,
+            days: i64,
+            is_holiday: impl Fn(&Time) -> bool,
+        ) -> Result<Time> {
+            let step = chrono::Duration::days(days.signum());
+            let mut datetime = self.signed_utc_datetime()?;
+            let mut remaining = days.unsigned_abs();
+            while remaining > 0 {
+                datetime = datetime
+                    .checked_add_signed(step)
+                    .ok_or(TimeError::InvalidTime)?;
+                if matches!(datetime.weekday(), Weekday::Sat | Weekday::Sun) {
+                    continue;
+                }
+                if !is_holiday(&Time::from(datetime)) {
+                    remaining -= 1;
+                }
+            }
+            Ok(Time::from(datetime))
         }
 
-        /// Adds two CustomDuration instances.
+        /// Creates a Time instance from a string in any of several common formats.
+        ///
+        /// The formats are tried in this order, and the first one that matches wins:
+        ///
+        /// - RFC 3339 (e.g. "2023-10-27T12:00:00Z")
+        /// - RFC 2822 (e.g. "Fri, 27 Oct 2023 12:00:00 +0000")
+        /// - `%Y-%m-%d %H:%M:%S%.f%z`
+        /// - `%Y-%m-%d %H:%M:%S%.f`
+        /// - `%Y-%m-%dT%H:%M:%S%.f`
+        /// - `%Y/%m/%d %H:%M:%S`
+        /// - `%Y-%m-%d %H:%M`
+        /// - `%Y-%m-%d`
+        /// - `%Y/%m/%d`
+        /// - `%d %b %Y`
+        ///
+        /// Formats without an offset are read as UTC, and dates without a time as midnight.
+        /// Returns a parse error if no format matches.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let inputs = [
+        ///     "2023-10-27T12:00:00Z",
+        ///     "2023-10-27T17:30:00+05:30",
+        ///     "Fri, 27 Oct 2023 12:00:00 +0000",
+        ///     "2023-10-27 12:00:00",
+        ///     "2023-10-27T12:00:00.000",
+        ///     "2023/10/27 12:00:00",
+        ///     "2023-10-27 12:00",
+        /// ];
+        /// for input in inputs {
+        ///     assert_eq!(Time::parse_flexible(input).unwrap().timestamp().unwrap(), 1698408000);
+        /// }
+        /// assert_eq!(Time::parse_flexible("2023/10/27").unwrap().timestamp().unwrap(), 1698364800);
+        /// assert!(Time::parse_flexible("not a time").is_err());
+        /// ```
+        pub fn parse_flexible(time_str: &str) -> Result<Self> {
+            let trimmed = time_str.trim();
+            FLEXIBLE_FORMATS
+                .iter()
+                .find_map(|(format, kind)| Time::parse_with_kind(trimmed, format, *kind))
+                .ok_or_else(|| {
+                    TimeError::ParseError(format!("Unrecognized time format: '{}'", time_str))
+                })
+        }
+
+        /// Returns the format from the `parse_flexible` catalog that matches the string, without
+        /// keeping the parsed value.
+        ///
+        /// RFC 3339 input is reported as `"%+"`. Returns `None` if no format matches.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// assert_eq!(Time::infer_format("2023-10-27T12:00:00Z"), Some("%+"));
+        /// assert_eq!(Time::infer_format("2023-10-27 12:00:00"), Some("%Y-%m-%d %H:%M:%S%.f"));
+        /// assert_eq!(Time::infer_format("27 Oct 2023"), Some("%d %b %Y"));
+        /// assert_eq!(Time::infer_format("yesterday"), None);
+        /// ```
+        pub fn infer_format(time_str: &str) -> Option<&'static str> {
+            let trimmed = time_str.trim();
+            FLEXIBLE_FORMATS
+                .iter()
+                .find(|(format, kind)| Time::parse_with_kind(trimmed, format, *kind).is_some())
+                .map(|(format, _)| *format)
+        }
+
+        // Helper function to parse a string with a format from the flexible parsing catalog
+        fn parse_with_kind(time_str: &str, format: &str, kind: FormatKind) -> Option<Self> {
+            let timestamp = match kind {
+                FormatKind::Rfc3339 => {
+                    SystemTime::from(DateTime::parse_from_rfc3339(time_str).ok()?)
+                }
+                FormatKind::Rfc2822 => {
+                    SystemTime::from(DateTime::parse_from_rfc2822(time_str).ok()?)
+                }
+                FormatKind::WithOffset => {
+                    SystemTime::from(DateTime::parse_from_str(time_str, format).ok()?)
+                }
+                FormatKind::Naive => {
+                    let naive = NaiveDateTime::parse_from_str(time_str, format).ok()?;
+                    SystemTime::from(DateTime::<Utc>::from_naive_utc_and_offset(naive, Utc))
+                }
+                FormatKind::DateOnly => {
+                    let date = NaiveDate::parse_from_str(time_str, format).ok()?;
+                    let naive = date.and_hms_opt(0, 0, 0)?;
+                    SystemTime::from(DateTime::<Utc>::from_naive_utc_and_offset(naive, Utc))
+                }
+            };
+            Some(Time {
+                timestamp,
+                cached_utc_datetime: None,
+            })
+        }
+
+        /// Appends the RFC 3339 representation of the time to the given buffer.
+        ///
+        /// This avoids allocating a new `String` for every timestamp on hot logging paths. The
+        /// output matches chrono's `to_rfc3339`, including for times before the Unix epoch.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{DateTime, Utc};
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:00:00.5+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+        /// let mut buf = String::with_capacity(64);
+        /// time.write_rfc3339(&mut buf).unwrap();
+        /// assert_eq!(buf, DateTime::<Utc>::try_from(time.clone()).unwrap().to_rfc3339());
+        /// buf.push(' ');
+        /// time.write_rfc3339(&mut buf).unwrap();
+        /// assert_eq!(buf, "2023-10-27T12:00:00.500+00:00 2023-10-27T12:00:00.500+00:00");
+        ///
+        /// let moon_landing = Time::from_timestamp_signed(-14_182_940).unwrap();
+        /// let mut buf = String::new();
+        /// moon_landing.write_rfc3339(&mut buf).unwrap();
+        /// assert_eq!(buf, "1969-07-20T20:17:40+00:00");
+        ///
+        /// let precise = Time::from_str("2023-10-27 12:00:00.000123+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+        /// let mut buf = String::new();
+        /// precise.write_rfc3339(&mut buf).unwrap();
+        /// assert_eq!(buf, "2023-10-27T12:00:00.000123+00:00");
+        /// ```
+        pub fn write_rfc3339(&self, buf: &mut String) -> Result<()> {
+            use std::fmt::Write;
+
+            let datetime = self.signed_utc_datetime()?;
+            let year = datetime.year();
+            if (0..=9999).contains(&year) {
+                write!(buf, "{:04}", year)
+            } else {
+                write!(buf, "{:+05}", year)
+            }
+            .and_then(|_| {
+                write!(
+                    buf,
+                    "-{:02}-{:02}T{:02}:{:02}:{:02}",
+                    datetime.month(),
+                    datetime.day(),
+                    datetime.hour(),
+                    datetime.minute(),
+                    datetime.second()
+                )
+            })
+            .and_then(|_| {
+                // Like chrono, print the fraction as milli-, micro- or nanoseconds as needed
+                let nanos = datetime.nanosecond();
+                if nanos == 0 {
+                    Ok(())
+                } else if nanos / 1_000_000 * 1_000_000 == nanos {
+                    write!(buf, ".{:03}", nanos / 1_000_000)
+                } else if nanos / 1_000 * 1_000 == nanos {
+                    write!(buf, ".{:06}", nanos / 1_000)
+                } else {
+                    write!(buf, ".{:09}", nanos)
+                }
+            })
+            .and_then(|_| buf.write_str("+00:00"))
+            .map_err(|_| TimeError::InvalidTime)
+        }
+
+        /// Rounds the time down to a multiple of the interval since the Unix epoch.
+        ///
+        /// Returns an error if the interval is zero or the time is invalid.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, Time};
+        /// let time = Time::from_str("2023-10-27 12:07:30+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let mut floored = time.floor_to(&CustomDuration::from_secs(300)).unwrap();
+        /// assert_eq!(floored.format("%H:%M:%S").unwrap(), "12:05:00");
+        /// assert!(time.floor_to(&CustomDuration::from_secs(0)).is_err());
+        /// ```
+        pub fn floor_to(&self, interval: &CustomDuration) -> Result<Time> {
+            let step = Time::interval_nanos(interval)?;
+            let nanos = self.timestamp_nanos()?;
+            Time::from_epoch_nanos(nanos - nanos % step)
+        }
+
+        /// Rounds the time up to a multiple of the interval since the Unix epoch.
+        ///
+        /// Returns an error if the interval is zero or the time is invalid.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, Time};
+        /// let time = Time::from_str("2023-10-27 12:07:30+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let mut ceiled = time.ceil_to(&CustomDuration::from_secs(300)).unwrap();
+        /// assert_eq!(ceiled.format("%H:%M:%S").unwrap(), "12:10:00");
+        /// ```
+        pub fn ceil_to(&self, interval: &CustomDuration) -> Result<Time> {
+            let step = Time::interval_nanos(interval)?;
+            let nanos = self.timestamp_nanos()?;
+            Time::from_epoch_nanos(nanos.div_ceil(step) * step)
+        }
+
+        /// Rounds the time to the nearest multiple of the interval since the Unix epoch,
+        /// rounding halves up.
+        ///
+        /// Returns an error if the interval is zero or the time is invalid.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, Time};
+        /// let time = Time::from_str("2023-10-27 12:07:30+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let mut rounded = time.round_to(&CustomDuration::from_secs(300)).unwrap();
+        /// assert_eq!(rounded.format("%H:%M:%S").unwrap(), "12:10:00");
+        /// let mut rounded = time.round_to(&CustomDuration::from_secs(3600)).unwrap();
+        /// assert_eq!(rounded.format("%H:%M:%S").unwrap(), "12:00:00");
+        /// ```
+        pub fn round_to(&self, interval: &CustomDuration) -> Result<Time> {
+            let step = Time::interval_nanos(interval)?;
+            let nanos = self.timestamp_nanos()?;
+            let floor = nanos - nanos % step;
+            if nanos % step >= step - step / 2 {
+                Time::from_epoch_nanos(floor + step)
+            } else {
+                Time::from_epoch_nanos(floor)
+            }
+        }
+
+        // Helper function to get a non-zero interval length in nanoseconds
+        fn interval_nanos(interval: &CustomDuration) -> Result<u128> {
+            match interval.duration.as_nanos() {
+                0 => Err(TimeError::InvalidTime),
+                nanos => Ok(nanos),
+            }
+        }
+
+        // Helper function to build a Time from nanoseconds since the Unix epoch
+        fn from_epoch_nanos(nanos: u128) -> Result<Time> {
+            let offset = CustomDuration::from_nanos_u128(nanos).duration;
+            SystemTime::UNIX_EPOCH
+                .checked_add(offset)
+                .map(|timestamp| Time {
+                    timestamp,
+                    cached_utc_datetime: None,
+                })
+                .ok_or(TimeError::InvalidTime)
+        }
+
+        /// Extracts the first timestamp matching the format from a log line.
+        ///
+        /// Each word boundary in the line is tried in order and the first position where the
+        /// format parses wins, so surrounding brackets or parentheses (e.g.
+        /// `[2023-10-27 12:00:00]`) are skipped. Formats without an offset are read as UTC.
+        ///
+        /// Returns an error if no timestamp matching the format is found.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let line = "INFO [2023-10-27 12:00:00] server started on port 8080";
+        /// let time = Time::extract_from_log_line(line, "%Y-%m-%d %H:%M:%S").unwrap();
+        /// assert_eq!(time.timestamp().unwrap(), 1698408000);
+        ///
+        /// let line = "worker-3 (2023-10-27T12:00:00Z) job finished (took 3s)";
+        /// let time = Time::extract_from_log_line(line, "%+").unwrap();
+        /// assert_eq!(time.timestamp().unwrap(), 1698408000);
+        ///
+        /// assert!(Time::extract_from_log_line("no timestamp here", "%Y-%m-%d %H:%M:%S").is_err());
+        /// ```
+        pub fn extract_from_log_line(line: &str, format: &str) -> Result<Self> {
+            let mut previous: Option<char> = None;
+            for (index, current) in line.char_indices() {
+                let at_boundary = !previous.is_some_and(|c| c.is_alphanumeric());
+                previous = Some(current);
+                if !at_boundary {
+                    continue;
+                }
+
+                if let Some(timestamp) = Time::parse_prefix(&line[index..], format) {
+                    return Ok(Time {
+                        timestamp,
+                        cached_utc_datetime: None,
+                    });
+                }
+            }
+            Err(TimeError::InvalidTimeFormat(format!(
+                "No timestamp matching '{}' found in '{}'",
+                format, line
+            )))
+        }
+
+        // Helper function to parse a timestamp at the start of a string, ignoring trailing text
+        fn parse_prefix(text: &str, format: &str) -> Option<SystemTime> {
+            if let Ok((dt, _)) = DateTime::parse_and_remainder(text, format) {
+                return Some(SystemTime::from(dt));
+            }
+            let (naive, _) = NaiveDateTime::parse_and_remainder(text, format).ok()?;
+            Some(SystemTime::from(
+                DateTime::<Utc>::from_naive_utc_and_offset(naive, Utc),
+            ))
+        }
+
+        /// Returns the time elapsed between this time and now.
+        ///
+        /// Returns an error if the time is in the future.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, Time};
+        /// let earlier = Time::now().sub_duration(&CustomDuration::from_secs(60));
+        /// assert!(earlier.elapsed().unwrap() >= CustomDuration::from_secs(60));
+        /// let later = Time::now().add_duration(&CustomDuration::from_secs(60));
+        /// assert!(later.elapsed().is_err());
+        /// ```
+        pub fn elapsed(&self) -> Result<CustomDuration> {
+            self.elapsed_with(&SystemClock)
+        }
+
+        /// Returns the time elapsed between this time and the current time of the given clock.
+        ///
+        /// Returns an error if the time is after the clock's time.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, FixedClock, Time};
+        /// let start = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let clock = FixedClock::new(&start.add_duration(&CustomDuration::from_secs(60)));
+        /// assert_eq!(start.elapsed_with(&clock).unwrap().as_secs(), 60);
+        /// let later = start.add_duration(&CustomDuration::from_secs(120));
+        /// assert!(later.elapsed_with(&clock).is_err());
+        /// ```
+        pub fn elapsed_with(&self, clock: &dyn Clock) -> Result<CustomDuration> {
+            clock
+                .now()
+                .duration_since(self.timestamp)
+                .map(|duration| CustomDuration { duration })
+                .map_err(|_| TimeError::InvalidTime)
+        }
+
+        /// Formats the time elapsed since this time into a human-readable string (e.g. "3h 12m").
+        ///
+        /// The age is truncated to whole seconds. Returns an error if the time is in the future.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, Time};
+        /// let two_hours_ago = Time::now().sub_duration(&CustomDuration::from_secs(7200));
+        /// let age = two_hours_ago.age_human_readable().unwrap();
+        /// assert!(age.contains("2h"));
+        /// println!("Last seen {} ago", age);
+        /// ```
+        #[cfg(feature = "humantime")]
+        pub fn age_human_readable(&self) -> Result<String> {
+            Ok(self.elapsed()?.round_secs().format_human_readable())
+        }
+
+        /// Formats the time as a fixed-width `YYYY-MM-DD HH:MM:SS.mmm` log prefix in UTC.
+        ///
+        /// The output is always 23 characters long, so log columns stay aligned.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:00:00.005+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+        /// assert_eq!(time.log_prefix().unwrap(), "2023-10-27 12:00:00.005");
+        /// let whole = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(whole.log_prefix().unwrap(), "2023-10-27 12:00:00.000");
+        /// assert_eq!(Time::now().log_prefix().unwrap().len(), 23);
+        /// ```
+        pub fn log_prefix(&self) -> Result<String> {
+            let datetime = self.utc_datetime()?;
+            Ok(datetime.format("%Y-%m-%d %H:%M:%S%.3f").to_string())
+        }
+
+        /// Describes the time relative to now in coarse, natural English.
+        ///
+        /// Times in the past are phrased as "… ago" and times in the future as "in …".
+        /// The difference from now is bucketed as follows:
+        ///
+        /// - under 1 minute: "just now"
+        /// - under 1 hour: whole minutes (e.g. "5 minutes ago")
+        /// - under 24 hours: whole hours (e.g. "in 3 hours")
+        /// - under 48 hours: "yesterday" or "tomorrow"
+        /// - under 30 days: whole days (e.g. "in 3 days")
+        /// - under 365 days: whole 30-day months (e.g. "2 months ago")
+        /// - otherwise: whole 365-day years (e.g. "1 year ago")
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, Time};
+        /// let ago = |secs: u64| Time::now().sub_duration(&CustomDuration::from_secs(secs));
+        /// let ahead = |secs: u64| Time::now().add_duration(&CustomDuration::from_secs(secs));
+        /// assert_eq!(ago(10).relative_phrase().unwrap(), "just now");
+        /// assert_eq!(ago(5 * 60 + 10).relative_phrase().unwrap(), "5 minutes ago");
+        /// assert_eq!(ago(3600 + 10).relative_phrase().unwrap(), "1 hour ago");
+        /// assert_eq!(ago(30 * 3600).relative_phrase().unwrap(), "yesterday");
+        /// assert_eq!(ahead(30 * 3600).relative_phrase().unwrap(), "tomorrow");
+        /// assert_eq!(ahead(3 * 86400 + 60).relative_phrase().unwrap(), "in 3 days");
+        /// assert_eq!(ago(65 * 86400).relative_phrase().unwrap(), "2 months ago");
+        /// assert_eq!(ahead(400 * 86400).relative_phrase().unwrap(), "in 1 year");
+        /// ```
+        pub fn relative_phrase(&self) -> Result<String> {
+            let (secs, past) = match SystemTime::now().duration_since(self.timestamp) {
+                Ok(duration) => (duration.as_secs(), true),
+                Err(e) => (e.duration().as_secs(), false),
+            };
+            let count = |value: u64, unit: &str| {
+                if value == 1 {
+                    format!("1 {}", unit)
+                } else {
+                    format!("{} {}s", value, unit)
+                }
+            };
+
+            let phrase = match secs {
+                0..=59 => return Ok("just now".to_string()),
+                60..=3_599 => count(secs / 60, "minute"),
+                3_600..=86_399 => count(secs / 3_600, "hour"),
+                86_400..=172_799 => {
+                    return Ok(if past { "yesterday" } else { "tomorrow" }.to_string())
+                }
+                172_800..=2_591_999 => count(secs / 86_400, "day"),
+                2_592_000..=31_535_999 => count(secs / 2_592_000, "month"),
+                _ => count(secs / 31_536_000, "year"),
+            };
+            if past {
+                Ok(format!("{} ago", phrase))
+            } else {
+                Ok(format!("in {}", phrase))
+            }
+        }
+
+        /// Checks that the time falls within `[start, end]`, inclusive.
+        ///
+        /// Returns an `OutOfRange` error naming the violated bound otherwise, which makes
+        /// time-based test assertions self-describing.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, Time};
+        /// let start = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let end = start.add_duration(&CustomDuration::from_secs(3600));
+        /// let inside = start.add_duration(&CustomDuration::from_secs(60));
+        /// assert!(inside.in_range(&start, &end).is_ok());
+        /// assert!(end.in_range(&start, &end).is_ok());
+        ///
+        /// let before = start.sub_duration(&CustomDuration::from_secs(1));
+        /// let err = before.in_range(&start, &end).unwrap_err();
+        /// assert!(err.to_string().contains("before the start"));
+        ///
+        /// let after = end.add_duration(&CustomDuration::from_secs(1));
+        /// let err = after.in_range(&start, &end).unwrap_err();
+        /// assert!(err.to_string().contains("after the end"));
+        /// ```
+        pub fn in_range(&self, start: &Time, end: &Time) -> Result<()> {
+            if self.timestamp < start.timestamp {
+                return Err(TimeError::OutOfRange(format!(
+                    "{} is before the start of the range ({})",
+                    self, start
+                )));
+            }
+            if self.timestamp > end.timestamp {
+                return Err(TimeError::OutOfRange(format!(
+                    "{} is after the end of the range ({})",
+                    self, end
+                )));
+            }
+            Ok(())
+        }
+
+        /// Returns a copy of the time with its sub-second part replaced by the given nanoseconds.
+        ///
+        /// Returns an error if `nanos` is not below one second or the time is invalid.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:00:00.123+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+        /// let cleared = time.with_subsec_nanos(0).unwrap();
+        /// assert_eq!(cleared.timestamp_nanos().unwrap(), 1698408000_000_000_000);
+        /// let half = time.with_subsec_nanos(500_000_000).unwrap();
+        /// assert_eq!(half.timestamp_nanos().unwrap(), 1698408000_500_000_000);
+        /// assert!(time.with_subsec_nanos(1_000_000_000).is_err());
+        ///
+        /// // Before the epoch the whole second is kept, so 23:59:59 becomes 23:59:59.25.
+        /// let before = Time::from_timestamp_signed(-1).unwrap();
+        /// let mut buf = String::new();
+        /// before.with_subsec_nanos(250_000_000).unwrap().write_rfc3339(&mut buf).unwrap();
+        /// assert_eq!(buf, "1969-12-31T23:59:59.250+00:00");
+        /// ```
+        pub fn with_subsec_nanos(&self, nanos: u32) -> Result<Time> {
+            if nanos >= 1_000_000_000 {
+                return Err(TimeError::InvalidTime);
+            }
+            let secs = i64::try_from(self.signed_nanos().div_euclid(1_000_000_000))
+                .map_err(|_| TimeError::InvalidTime)?;
+            Time::from_signed_secs(secs, nanos)
+        }
+
+        /// Returns the number of whole days since the Unix epoch, rounding down.
+        ///
+        /// Times before the epoch yield negative day numbers.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(time.unix_day().unwrap(), 19657);
+        /// let before = Time::from_str("1969-12-31 23:59:59+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(before.unix_day().unwrap(), -1);
+        /// ```
+        pub fn unix_day(&self) -> Result<i64> {
+            let day = self.signed_nanos().div_euclid(86_400_000_000_000);
+            i64::try_from(day).map_err(|_| TimeError::InvalidTime)
+        }
+
+        /// Creates a Time instance at midnight UTC of the given day since the Unix epoch.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let mut day = Time::from_unix_day(19657).unwrap();
+        /// assert_eq!(day.format("%Y-%m-%d %H:%M:%S").unwrap(), "2023-10-27 00:00:00");
+        /// assert_eq!(day.unix_day().unwrap(), 19657);
+        /// let before = Time::from_unix_day(-365).unwrap();
+        /// assert_eq!(before.unix_day().unwrap(), -365);
+        /// let expected = Time::from_str("1969-01-01 00:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(before.as_system_time(), expected.as_system_time());
+        /// ```
+        pub fn from_unix_day(days: i64) -> Result<Time> {
+            let secs = days.checked_mul(86_400).ok_or(TimeError::InvalidTime)?;
+            Time::from_signed_secs(secs, 0)
+        }
+
+        // Helper function to get the signed number of nanoseconds since the Unix epoch
+        fn signed_nanos(&self) -> i128 {
+            match self.timestamp.duration_since(SystemTime::UNIX_EPOCH) {
+                Ok(duration) => duration.as_nanos() as i128,
+                Err(e) => -(e.duration().as_nanos() as i128),
+            }
+        }
+
+        // Helper function to build a Time from signed seconds and nanoseconds since the Unix epoch
+        fn from_signed_secs(secs: i64, nanos: u32) -> Result<Time> {
+            let timestamp = if secs >= 0 {
+                SystemTime::UNIX_EPOCH.checked_add(Duration::new(secs as u64, nanos))
+            } else {
+                SystemTime::UNIX_EPOCH
+                    .checked_sub(Duration::from_secs(secs.unsigned_abs()))
+                    .and_then(|t| t.checked_add(Duration::from_nanos(nanos as u64)))
+            };
+            timestamp
+                .map(Time::from_system_time)
+                .ok_or(TimeError::InvalidTime)
+        }
+
+        /// Returns an iterator over `count` times spaced by `step`, starting at `start`.
+        ///
+        /// A zero step yields an empty iterator, and the iterator stops early if a time
+        /// would overflow.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, Time};
+        /// let start = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let ticks: Vec<Time> = Time::range(start, CustomDuration::from_secs(900), 4).collect();
+        /// let timestamps: Vec<u64> = ticks.iter().map(|t| t.timestamp().unwrap()).collect();
+        /// assert_eq!(timestamps, vec![1698408000, 1698408900, 1698409800, 1698410700]);
+        /// let start = Time::now();
+        /// assert_eq!(Time::range(start, CustomDuration::from_secs(0), 4).count(), 0);
+        /// ```
+        pub fn range(
+            start: Time,
+            step: CustomDuration,
+            count: usize,
+        ) -> impl Iterator<Item = Time> {
+            let count = if step.duration.is_zero() { 0 } else { count };
+            std::iter::successors(Some(start), move |time| {
+                time.timestamp
+                    .checked_add(step.duration)
+                    .map(Time::from_system_time)
+            })
+            .take(count)
+        }
+
+        /// Formats the time in UTC with era-aware years, for dates that may fall before 1 CE.
+        ///
+        /// Two tokens are handled specially: `%E` expands to the era ("CE" or "BCE"), and `%Y`
+        /// expands to the unpadded year of that era, so 1 BCE follows 1 CE with no year zero.
+        /// All other tokens are passed through to the regular formatter. Unlike `format`, this
+        /// also works for times before the Unix epoch.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{TimeZone, Utc};
+        /// use time_duration_api::time_utils::Time;
+        /// let modern = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(modern.format_with_era("%d %B %Y %E").unwrap(), "27 October 2023 CE");
+        /// let ides = Time::from(Utc.with_ymd_and_hms(-43, 3, 15, 12, 0, 0).unwrap());
+        /// assert_eq!(ides.format_with_era("%d %B %Y %E").unwrap(), "15 March 44 BCE");
+        /// ```
+        pub fn format_with_era(&self, format: &str) -> Result<String> {
+            let datetime = self.signed_utc_datetime()?;
+            let (is_ce, year) = datetime.year_ce();
+
+            let mut expanded = String::with_capacity(format.len());
+            let mut chars = format.chars();
+            while let Some(c) = chars.next() {
+                if c != '%' {
+                    expanded.push(c);
+                    continue;
+                }
+                match chars.next() {
+                    Some('E') => expanded.push_str(if is_ce { "CE" } else { "BCE" }),
+                    Some('Y') => expanded.push_str(&year.to_string()),
+                    Some(other) => {
+                        expanded.push('%');
+                        expanded.push(other);
+                    }
+                    None => expanded.push('%'),
+                }
+            }
+            Ok(datetime.format(&expanded).to_string())
+        }
+
+        // Helper function to generate DateTime<Utc>, including for times before the Unix epoch
+        fn signed_utc_datetime(&self) -> Result<DateTime<Utc>> {
+            let nanos = self.signed_nanos();
+            let secs = i64::try_from(nanos.div_euclid(1_000_000_000))
+                .map_err(|_| TimeError::InvalidTime)?;
+            let subsec_nanos = nanos.rem_euclid(1_000_000_000) as u32;
+            DateTime::<Utc>::from_timestamp(secs, subsec_nanos).ok_or(TimeError::InvalidTime)
+        }
+
+        /// Formats the time in UTC with month and weekday names in the given locale.
+        ///
+        /// `locale` is a POSIX locale name such as `"fr_FR"` or `"de_DE"`. Returns a
+        /// `ParseError` if the locale is not recognised.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(time.format_localized("%A %d %B %Y", "fr_FR").unwrap(), "vendredi 27 octobre 2023");
+        /// assert!(time.format_localized("%B", "xx_XX").is_err());
+        /// ```
+        #[cfg(feature = "locales")]
+        pub fn format_localized(&self, format: &str, locale: &str) -> Result<String> {
+            let datetime = self.utc_datetime()?;
+            let locale = chrono::Locale::try_from(locale)
+                .map_err(|_| TimeError::ParseError(format!("Unknown locale: {}", locale)))?;
+            Ok(datetime.format_localized(format, locale).to_string())
+        }
+
+        /// Returns whether the UTC year of this time is a leap year.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let leap = Time::from_str("2024-02-10 00:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let common = Time::from_str("2023-02-10 00:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert!(leap.is_leap_year().unwrap());
+        /// assert!(!common.is_leap_year().unwrap());
+        ///
+        /// // Times before 1970 work too.
+        /// let y1900 = Time::from_str("1900-06-01 00:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let y1960 = Time::from_str("1960-06-01 00:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert!(!y1900.is_leap_year().unwrap());
+        /// assert!(y1960.is_leap_year().unwrap());
+        /// ```
+        pub fn is_leap_year(&self) -> Result<bool> {
+            let year = self.signed_utc_datetime()?.year();
+            Ok(year % 4 == 0 && (year % 100 != 0 || year % 400 == 0))
+        }
+
+        /// Returns the number of days in the UTC month of this time.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let leap = Time::from_str("2024-02-10 00:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let common = Time::from_str("2023-02-10 00:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let december = Time::from_str("2023-12-31 23:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(leap.days_in_month().unwrap(), 29);
+        /// assert_eq!(common.days_in_month().unwrap(), 28);
+        /// assert_eq!(december.days_in_month().unwrap(), 31);
+        ///
+        /// let feb_1900 = Time::from_str("1900-02-10 00:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let feb_1960 = Time::from_str("1960-02-10 00:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(feb_1900.days_in_month().unwrap(), 28);
+        /// assert_eq!(feb_1960.days_in_month().unwrap(), 29);
+        /// ```
+        pub fn days_in_month(&self) -> Result<u32> {
+            let datetime = self.signed_utc_datetime()?;
+            let (year, month) = if datetime.month() == 12 {
+                (datetime.year() + 1, 1)
+            } else {
+                (datetime.year(), datetime.month() + 1)
+            };
+            let next_month =
+                NaiveDate::from_ymd_opt(year, month, 1).ok_or(TimeError::InvalidTime)?;
+            let this_month = NaiveDate::from_ymd_opt(datetime.year(), datetime.month(), 1)
+                .ok_or(TimeError::InvalidTime)?;
+            Ok((next_month - this_month).num_days() as u32)
+        }
+
+        /// Returns a time on this time's UTC date with the UTC time of day of `other`.
+        ///
+        /// The hour, minute, second, and nanosecond are all taken from `other`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{DateTime, Utc};
+        /// use time_duration_api::time_utils::Time;
+        /// let date = Time::from_str("2023-10-27 23:59:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let clock = Time::from_str("2001-01-01 09:30:15+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let combined = date.with_clock_from(&clock).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(combined.clone()).unwrap().format("%Y-%m-%d %H:%M:%S").to_string(), "2023-10-27 09:30:15");
+        /// ```
+        pub fn with_clock_from(&self, other: &Time) -> Result<Time> {
+            let date = self.signed_utc_datetime()?.date_naive();
+            let clock = other.signed_utc_datetime()?.time();
+            Ok(Time::from(date.and_time(clock).and_utc()))
+        }
+
+        /// Returns the calendar difference between this time and `other` as
+        /// `(years, months, days, hours, minutes, seconds)`.
+        ///
+        /// Whole months are counted first on the UTC dates, so month lengths are taken into
+        /// account, and the remainder is split into fixed-length units. Sub-second parts are
+        /// dropped. All components are negative if this time is before `other`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let start = Time::from_str("2023-01-15 10:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let year_later = Time::from_str("2024-01-15 10:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let month_and_day = Time::from_str("2023-02-16 10:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let mixed = Time::from_str("2023-03-17 13:04:05+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(year_later.calendar_diff(&start).unwrap(), (1, 0, 0, 0, 0, 0));
+        /// assert_eq!(month_and_day.calendar_diff(&start).unwrap(), (0, 1, 1, 0, 0, 0));
+        /// assert_eq!(mixed.calendar_diff(&start).unwrap(), (0, 2, 2, 3, 4, 5));
+        /// assert_eq!(start.calendar_diff(&month_and_day).unwrap(), (0, -1, -1, 0, 0, 0));
+        /// ```
+        pub fn calendar_diff(&self, other: &Time) -> Result<(i64, i64, i64, i64, i64, i64)> {
+            let this = self.signed_utc_datetime()?;
+            let other = other.signed_utc_datetime()?;
+            let (earlier, later, sign) = if this >= other {
+                (other, this, 1)
+            } else {
+                (this, other, -1)
+            };
+
+            let mut months = (later.year() - earlier.year()) as i64 * 12 + later.month() as i64
+                - earlier.month() as i64;
+            let add_months = |months: i64| {
+                earlier
+                    .checked_add_months(chrono::Months::new(months as u32))
+                    .ok_or(TimeError::InvalidTime)
+            };
+            let mut anchor = add_months(months)?;
+            if anchor > later {
+                months -= 1;
+                anchor = add_months(months)?;
+            }
+
+            let secs = (later - anchor).num_seconds();
+            Ok((
+                sign * (months / 12),
+                sign * (months % 12),
+                sign * (secs / 86_400),
+                sign * (secs % 86_400 / 3600),
+                sign * (secs % 3600 / 60),
+                sign * (secs % 60),
+            ))
+        }
+
+        /// Truncates the time to the start of its UTC minute.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{DateTime, Utc};
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:07:30.250+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+        /// let minute = time.truncate_to_minute().unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(minute.clone()).unwrap().format("%H:%M:%S%.f").to_string(), "12:07:00");
+        /// ```
+        pub fn truncate_to_minute(&self) -> Result<Time> {
+            self.truncate_to(TimeUnit::Minute)
+        }
+
+        /// Truncates the time to the start of its UTC hour.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{DateTime, Utc};
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:07:30.250+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+        /// let hour = time.truncate_to_hour().unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(hour.clone()).unwrap().format("%H:%M:%S%.f").to_string(), "12:00:00");
+        /// ```
+        pub fn truncate_to_hour(&self) -> Result<Time> {
+            self.truncate_to(TimeUnit::Hour)
+        }
+
+        /// Truncates the time to the start of its UTC second, minute, hour, or day.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{DateTime, Utc};
+        /// use time_duration_api::time_utils::{Time, TimeUnit};
+        /// let time = Time::from_str("2023-10-27 12:07:30.250+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+        /// let format = |unit| DateTime::<Utc>::try_from(time.truncate_to(unit).unwrap()).unwrap().format("%d %H:%M:%S%.3f").to_string();
+        /// assert_eq!(format(TimeUnit::Second), "27 12:07:30.000");
+        /// assert_eq!(format(TimeUnit::Minute), "27 12:07:00.000");
+        /// assert_eq!(format(TimeUnit::Hour), "27 12:00:00.000");
+        /// assert_eq!(format(TimeUnit::Day), "27 00:00:00.000");
+        /// ```
+        pub fn truncate_to(&self, unit: TimeUnit) -> Result<Time> {
+            let datetime = self.signed_utc_datetime()?;
+            let (hour, minute, second) = match unit {
+                TimeUnit::Second => (datetime.hour(), datetime.minute(), datetime.second()),
+                TimeUnit::Minute => (datetime.hour(), datetime.minute(), 0),
+                TimeUnit::Hour => (datetime.hour(), 0, 0),
+                TimeUnit::Day => (0, 0, 0),
+            };
+            let truncated = datetime
+                .date_naive()
+                .and_hms_opt(hour, minute, second)
+                .ok_or(TimeError::InvalidTime)?;
+            Ok(Time::from(truncated.and_utc()))
+        }
+
+        /// Gets the signed timestamp in whole seconds, which is negative for times before
+        /// the Unix epoch.
+        ///
+        /// Fractional seconds are rounded down, so half a second before the epoch is `-1`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let moon_landing = Time::from_str("1969-07-20 20:17:40+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(moon_landing.timestamp_signed(), -14182940);
+        /// let round_trip = Time::from_timestamp_signed(-14182940).unwrap();
+        /// assert_eq!(round_trip.as_system_time(), moon_landing.as_system_time());
+        /// ```
+        pub fn timestamp_signed(&self) -> i64 {
+            self.signed_nanos().div_euclid(1_000_000_000) as i64
+        }
+
+        /// Creates a Time from a signed timestamp in seconds, accepting times before the
+        /// Unix epoch.
+        ///
+        /// Returns an error if the timestamp cannot be represented.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{DateTime, Utc};
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_timestamp_signed(-86400).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(time.clone()).unwrap().format("%Y-%m-%d").to_string(), "1969-12-31");
+        /// assert_eq!(time.timestamp_signed(), -86400);
+        /// ```
+        pub fn from_timestamp_signed(secs: i64) -> Result<Time> {
+            Time::from_signed_secs(secs, 0)
+        }
+
+        /// Returns the next occurrence of the weekday strictly after this time's UTC date,
+        /// at the same time of day.
+        ///
+        /// If this time already falls on `weekday`, the result is one week later.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{DateTime, Utc, Weekday};
+        /// use time_duration_api::time_utils::Time;
+        /// let friday = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let monday = friday.next_weekday(Weekday::Mon).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(monday.clone()).unwrap().format("%Y-%m-%d %H:%M").to_string(), "2023-10-30 12:00");
+        /// let next_friday = friday.next_weekday(Weekday::Fri).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(next_friday.clone()).unwrap().format("%Y-%m-%d").to_string(), "2023-11-03");
+        /// ```
+        pub fn next_weekday(&self, weekday: Weekday) -> Result<Time> {
+            let datetime = self.signed_utc_datetime()?;
+            let days = match Time::days_between_weekdays(datetime.weekday(), weekday) {
+                0 => 7,
+                days => days,
+            };
+            datetime
+                .checked_add_signed(chrono::Duration::days(days as i64))
+                .map(Time::from)
+                .ok_or(TimeError::InvalidTime)
+        }
+
+        /// Returns the previous occurrence of the weekday strictly before this time's UTC
+        /// date, at the same time of day.
+        ///
+        /// If this time already falls on `weekday`, the result is one week earlier.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{DateTime, Utc, Weekday};
+        /// use time_duration_api::time_utils::Time;
+        /// let friday = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let wednesday = friday.previous_weekday(Weekday::Wed).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(wednesday.clone()).unwrap().format("%Y-%m-%d %H:%M").to_string(), "2023-10-25 12:00");
+        /// let last_friday = friday.previous_weekday(Weekday::Fri).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(last_friday.clone()).unwrap().format("%Y-%m-%d").to_string(), "2023-10-20");
+        /// ```
+        pub fn previous_weekday(&self, weekday: Weekday) -> Result<Time> {
+            let datetime = self.signed_utc_datetime()?;
+            let days = match Time::days_between_weekdays(weekday, datetime.weekday()) {
+                0 => 7,
+                days => days,
+            };
+            datetime
+                .checked_sub_signed(chrono::Duration::days(days as i64))
+                .map(Time::from)
+                .ok_or(TimeError::InvalidTime)
+        }
+
+        // Helper function to count the days from one weekday forward to another, from 0 to 6
+        fn days_between_weekdays(from: Weekday, to: Weekday) -> u32 {
+            (7 + to.num_days_from_monday() - from.num_days_from_monday()) % 7
+        }
+
+        /// Parses a time with `parse_flexible`, falling back to the Unix epoch if the string
+        /// is not recognised.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// assert_eq!(Time::parse_or_epoch("2023-10-27T12:00:00Z").timestamp().unwrap(), 1698408000);
+        /// assert_eq!(Time::parse_or_epoch("not a time").timestamp().unwrap(), 0);
+        /// ```
+        pub fn parse_or_epoch(time_str: &str) -> Time {
+            Time::parse_flexible(time_str)
+                .unwrap_or_else(|_| Time::from_system_time(SystemTime::UNIX_EPOCH))
+        }
+
+        /// Returns the time halfway between two times, in either order.
+        ///
+        /// Times before the Unix epoch are supported, and an odd nanosecond is rounded toward
+        /// the earlier time.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let start = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let end = Time::from_str("2023-10-27 13:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let middle = Time::midpoint(&start, &end).unwrap();
+        /// assert_eq!(middle.timestamp().unwrap() - start.timestamp().unwrap(), 1800);
+        /// assert_eq!(end.timestamp().unwrap() - middle.timestamp().unwrap(), 1800);
+        /// let before = Time::from_timestamp_signed(-100).unwrap();
+        /// let after = Time::from_timestamp_signed(40).unwrap();
+        /// assert_eq!(Time::midpoint(&after, &before).unwrap().timestamp_signed(), -30);
+        /// ```
+        pub fn midpoint(a: &Time, b: &Time) -> Result<Time> {
+            let nanos = (a.signed_nanos() + b.signed_nanos()).div_euclid(2);
+            let secs = i64::try_from(nanos.div_euclid(1_000_000_000))
+                .map_err(|_| TimeError::InvalidTime)?;
+            Time::from_signed_secs(secs, nanos.rem_euclid(1_000_000_000) as u32)
+        }
+
+        /// Formats the time with a given format string at a UTC offset given in seconds east
+        /// of UTC (e.g. 19800 for "+05:30").
+        ///
+        /// Returns an `InvalidTimezoneFormat` error if the offset is a day or more.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{Time, TimeError};
+        /// let time = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(
+        ///     time.format_with_offset_seconds("%Y-%m-%d %H:%M %:z", 19800).unwrap(),
+        ///     "2023-10-27 17:30 +05:30"
+        /// );
+        /// assert_eq!(
+        ///     time.format_with_offset_seconds("%H:%M", 86400),
+        ///     Err(TimeError::InvalidTimezoneFormat("86400".to_string()))
+        /// );
+        /// ```
+        pub fn format_with_offset_seconds(&self, format: &str, offset_secs: i32) -> Result<String> {
+            let datetime = self.utc_datetime()?;
+            let tz = FixedOffset::east_opt(offset_secs)
+                .ok_or_else(|| TimeError::InvalidTimezoneFormat(offset_secs.to_string()))?;
+            Ok(datetime.with_timezone(&tz).format(format).to_string())
+        }
+
+        /// Returns a time on this time's UTC date at the given UTC hour, minute, and second.
+        ///
+        /// Returns an `InvalidTime` error if any field is out of range.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{DateTime, Utc};
+        /// use time_duration_api::time_utils::{Time, TimeError};
+        /// let time = Time::from_str("2023-10-27 17:45:12+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let morning = time.with_time_of_day(9, 0, 0).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(morning.clone()).unwrap().format("%Y-%m-%d %H:%M:%S").to_string(), "2023-10-27 09:00:00");
+        /// assert_eq!(time.with_time_of_day(24, 0, 0).unwrap_err(), TimeError::InvalidTime);
+        /// assert!(time.with_time_of_day(9, 60, 0).is_err());
+        /// ```
+        pub fn with_time_of_day(&self, hour: u32, minute: u32, second: u32) -> Result<Time> {
+            let datetime = self
+                .signed_utc_datetime()?
+                .date_naive()
+                .and_hms_opt(hour, minute, second)
+                .ok_or(TimeError::InvalidTime)?;
+            Ok(Time::from(datetime.and_utc()))
+        }
+
+        /// Parses every line with `from_str`, collecting the successes in order along with the
+        /// index and error of each line that failed.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let lines = [
+        ///     "2023-10-27 12:00:00+00:00",
+        ///     "garbage",
+        ///     "2023-10-27 13:00:00+00:00",
+        ///     "",
+        /// ];
+        /// let (times, errors) = Time::parse_many(&lines, "%Y-%m-%d %H:%M:%S%:z");
+        /// let secs: Vec<u64> = times.iter().map(|time| time.timestamp().unwrap()).collect();
+        /// assert_eq!(secs, vec![1698408000, 1698411600]);
+        /// let failed: Vec<usize> = errors.iter().map(|(index, _)| *index).collect();
+        /// assert_eq!(failed, vec![1, 3]);
+        /// ```
+        pub fn parse_many(lines: &[&str], format: &str) -> (Vec<Time>, Vec<(usize, TimeError)>) {
+            let mut times = Vec::with_capacity(lines.len());
+            let mut errors = Vec::new();
+            for (index, line) in lines.iter().enumerate() {
+                match Time::from_str(line, format) {
+                    Ok(time) => times.push(time),
+                    Err(e) => errors.push((index, e)),
+                }
+            }
+            (times, errors)
+        }
+
+        /// Classifies how long ago this time was into an `AgeBucket`.
+        ///
+        /// Returns an error if the time is in the future.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{AgeBucket, CustomDuration, Time};
+        /// let ago = |secs: u64| Time::now().sub_duration(&CustomDuration::from_secs(secs));
+        /// assert_eq!(ago(5).age_bucket().unwrap(), AgeBucket::JustNow);
+        /// assert_eq!(ago(600).age_bucket().unwrap(), AgeBucket::Minutes);
+        /// assert_eq!(ago(5 * 3600).age_bucket().unwrap(), AgeBucket::Hours);
+        /// assert_eq!(ago(3 * 86400).age_bucket().unwrap(), AgeBucket::Days);
+        /// assert_eq!(ago(14 * 86400).age_bucket().unwrap(), AgeBucket::Weeks);
+        /// assert_eq!(ago(90 * 86400).age_bucket().unwrap(), AgeBucket::Older);
+        /// assert!(Time::now().add_duration(&CustomDuration::from_secs(60)).age_bucket().is_err());
+        /// ```
+        pub fn age_bucket(&self) -> Result<AgeBucket> {
+            self.elapsed().map(|age| AgeBucket::from_age(&age))
+        }
+
+        /// Converts the time to a fractional Julian Date.
+        ///
+        /// The Julian Date counts days since noon UTC on 1 January 4713 BCE (Julian calendar).
+        /// It is computed as `2440587.5 + unix_seconds / 86400`, since the Unix epoch is
+        /// JD 2440587.5. An `f64` Julian Date is precise to roughly ten microseconds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let j2000 = Time::from_str("2000-01-01 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(j2000.to_julian_day().unwrap(), 2451545.0);
+        /// assert_eq!(Time::default().to_julian_day().unwrap(), 2440587.5);
+        /// ```
+        pub fn to_julian_day(&self) -> Result<f64> {
+            Ok(UNIX_EPOCH_JULIAN_DAY + self.signed_nanos() as f64 / NANOS_PER_DAY)
+        }
+
+        /// Creates a Time from a fractional Julian Date, rounded to the nearest microsecond.
+        ///
+        /// See `to_julian_day` for the conversion. Returns an error if the Julian Date is not
+        /// finite or is out of range.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{DateTime, Utc};
+        /// use time_duration_api::time_utils::Time;
+        /// let j2000 = Time::from_julian_day(2451545.0).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(j2000.clone()).unwrap().format("%Y-%m-%d %H:%M:%S").to_string(), "2000-01-01 12:00:00");
+        /// let time = Time::from_str("2023-10-27 18:30:45+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let round_trip = Time::from_julian_day(time.to_julian_day().unwrap()).unwrap();
+        /// let error = round_trip.timestamp_nanos().unwrap().abs_diff(time.timestamp_nanos().unwrap());
+        /// assert!(error < 100_000);
+        /// assert!(Time::from_julian_day(f64::NAN).is_err());
+        /// ```
+        pub fn from_julian_day(julian_day: f64) -> Result<Time> {
+            let micros = ((julian_day - UNIX_EPOCH_JULIAN_DAY) * NANOS_PER_DAY / 1000.0).round();
+            if !micros.is_finite() || micros.abs() >= i64::MAX as f64 {
+                return Err(TimeError::InvalidTime);
+            }
+            let micros = micros as i64;
+            Time::from_signed_secs(
+                micros.div_euclid(1_000_000),
+                micros.rem_euclid(1_000_000) as u32 * 1000,
+            )
+        }
+
+        /// Returns the ISO 8601 week-numbering year and week of this time's UTC date.
+        ///
+        /// Near the start and end of a year, the ISO year can differ from the calendar year.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(time.iso_week().unwrap(), (2023, 43));
+        /// let new_year = Time::from_str("2021-01-01 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(new_year.iso_week().unwrap(), (2020, 53));
+        /// ```
+        pub fn iso_week(&self) -> Result<(i32, u32)> {
+            let week = self.signed_utc_datetime()?.iso_week();
+            Ok((week.year(), week.week()))
+        }
+
+        /// Formats this time's ISO 8601 week as `YYYY-Www` (e.g. "2023-W43").
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(time.format_iso_week().unwrap(), "2023-W43");
+        /// let new_year = Time::from_str("2021-01-03 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(new_year.format_iso_week().unwrap(), "2020-W53");
+        /// let early = Time::from_str("2024-01-01 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(early.format_iso_week().unwrap(), "2024-W01");
+        /// ```
+        pub fn format_iso_week(&self) -> Result<String> {
+            let (year, week) = self.iso_week()?;
+            Ok(format!("{:04}-W{:02}", year, week))
+        }
+
+        /// Gets the timestamp as fractional seconds since the Unix epoch, negative for times
+        /// before it.
+        ///
+        /// An `f64` holds about 15 to 16 significant digits, so present-day timestamps keep
+        /// roughly microsecond precision and nanoseconds are lost. Use `timestamp_nanos` when
+        /// exact values matter.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, Time};
+        /// let time = Time::from_timestamp_signed(1698408000).unwrap();
+        /// let later = time.add_duration(&CustomDuration::from_millis(500));
+        /// assert_eq!(later.timestamp_f64().unwrap(), 1698408000.5);
+        /// let before = Time::from_timestamp_signed(-2).unwrap().add_duration(&CustomDuration::from_millis(500));
+        /// assert_eq!(before.timestamp_f64().unwrap(), -1.5);
+        /// ```
+        pub fn timestamp_f64(&self) -> Result<f64> {
+            let nanos = self.signed_nanos();
+            Ok(nanos.div_euclid(1_000_000_000) as f64
+                + nanos.rem_euclid(1_000_000_000) as f64 / 1e9)
+        }
+
+        /// Returns whether this time and `other` fall on the same UTC calendar day.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let before = Time::from_str("2023-10-27 23:58:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let after = Time::from_str("2023-10-28 00:02:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let noon = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert!(!before.is_same_day(&after).unwrap());
+        /// assert!(before.is_same_day(&noon).unwrap());
+        /// ```
+        pub fn is_same_day(&self, other: &Time) -> Result<bool> {
+            let (this, other) = (self.signed_utc_datetime()?, other.signed_utc_datetime()?);
+            Ok(this.date_naive() == other.date_naive())
+        }
+
+        /// Returns whether this time and `other` fall in the same UTC calendar month.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let october = Time::from_str("2023-10-31 23:58:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let november = Time::from_str("2023-11-01 00:02:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let last_year = Time::from_str("2022-10-15 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert!(!october.is_same_month(&november).unwrap());
+        /// assert!(!october.is_same_month(&last_year).unwrap());
+        /// ```
+        pub fn is_same_month(&self, other: &Time) -> Result<bool> {
+            let (this, other) = (self.signed_utc_datetime()?, other.signed_utc_datetime()?);
+            Ok(this.year() == other.year() && this.month() == other.month())
+        }
+
+        /// Returns whether this time and `other` fall in the same UTC calendar year.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let old_year = Time::from_str("2023-12-31 23:58:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let new_year = Time::from_str("2024-01-01 00:02:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let spring = Time::from_str("2023-03-01 00:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert!(!old_year.is_same_year(&new_year).unwrap());
+        /// assert!(old_year.is_same_year(&spring).unwrap());
+        /// ```
+        pub fn is_same_year(&self, other: &Time) -> Result<bool> {
+            let (this, other) = (self.signed_utc_datetime()?, other.signed_utc_datetime()?);
+            Ok(this.year() == other.year())
+        }
+
+        /// Formats the time in UTC for use in file names, as `YYYY-MM-DD_HH-MM-SS`.
+        ///
+        /// The result contains no colons or other characters that are invalid in Windows
+        /// file names.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:34:56+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let name = time.to_filename_string().unwrap();
+        /// assert_eq!(name, "2023-10-27_12-34-56");
+        /// assert!(!name.contains(':'));
+        /// let parsed = Time::from_str_assume_utc(&name, "%Y-%m-%d_%H-%M-%S").unwrap();
+        /// assert_eq!(parsed, time);
+        /// ```
+        pub fn to_filename_string(&self) -> Result<String> {
+            self.to_filename_string_with("_")
+        }
+
+        /// Formats the time in UTC for use in file names, with a custom separator between the
+        /// date and the time (e.g. `YYYY-MM-DDTHH-MM-SS` for "T").
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:34:56+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(time.to_filename_string_with("T").unwrap(), "2023-10-27T12-34-56");
+        /// ```
+        pub fn to_filename_string_with(&self, separator: &str) -> Result<String> {
+            let datetime = self.signed_utc_datetime()?;
+            Ok(format!(
+                "{}{}{}",
+                datetime.format("%Y-%m-%d"),
+                separator,
+                datetime.format("%H-%M-%S")
+            ))
+        }
+
+        /// Returns this time limited to the range from `min` to `max`, inclusive.
+        ///
+        /// Unlike `Ord::clamp`, this borrows the time and the bounds. In debug builds this
+        /// panics if `min` is greater than `max`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let at = |secs| Time::from_timestamp_signed(secs).unwrap();
+        /// let (min, max) = (at(100), at(200));
+        /// assert_eq!(at(50).clamp_to(&min, &max), min);
+        /// assert_eq!(at(150).clamp_to(&min, &max), at(150));
+        /// assert_eq!(at(250).clamp_to(&min, &max), max);
+        ///
+        /// // `Ord::clamp` is still available for owned bounds.
+        /// assert_eq!(at(250).clamp(at(100), at(200)), at(200));
+        /// ```
+        pub fn clamp_to(&self, min: &Time, max: &Time) -> Time {
+            debug_assert!(min <= max, "clamp requires min <= max");
+            if self < min {
+                min.clone()
+            } else if self > max {
+                max.clone()
+            } else {
+                self.clone()
+            }
+        }
+
+        /// Returns the signed difference `self - other` as a `chrono::Duration`, which is
+        /// negative if this time is before `other`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let earlier = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let later = Time::from_str("2023-10-27 13:30:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(later.signed_diff(&earlier).unwrap(), chrono::Duration::minutes(90));
+        /// assert_eq!(earlier.signed_diff(&later).unwrap(), chrono::Duration::minutes(-90));
+        /// ```
+        pub fn signed_diff(&self, other: &Time) -> Result<chrono::Duration> {
+            Ok(self.signed_utc_datetime()? - other.signed_utc_datetime()?)
+        }
+
+        /// Returns the civil `(year, month, day, hour, minute, second)` of this time at a
+        /// fixed UTC offset such as "+05:30".
+        ///
+        /// Returns an `InvalidTimezoneFormat` error if the offset is invalid.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 20:45:10+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// assert_eq!(time.to_timezone_parts("+05:30").unwrap(), (2023, 10, 28, 2, 15, 10));
+        /// assert_eq!(time.to_timezone_parts("-08:00").unwrap(), (2023, 10, 27, 12, 45, 10));
+        /// assert!(time.to_timezone_parts("Mars/Olympus").is_err());
+        /// ```
+        pub fn to_timezone_parts(&self, timezone: &str) -> Result<(i32, u32, u32, u32, u32, u32)> {
+            let tz = parse_fixed_offset(timezone)?;
+            let local = self.signed_utc_datetime()?.with_timezone(&tz);
+            Ok((
+                local.year(),
+                local.month(),
+                local.day(),
+                local.hour(),
+                local.minute(),
+                local.second(),
+            ))
+        }
+
+        /// Returns several common representations of the time at once, for debugging.
+        ///
+        /// Returns an `OutOfRange` error if the UTC year is outside 0 to 9999, which RFC 2822
+        /// cannot represent.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:00:00.250+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+        /// let repr = time.format_all().unwrap();
+        /// assert_eq!(repr.rfc3339, "2023-10-27T12:00:00.250+00:00");
+        /// assert_eq!(repr.rfc2822, "Fri, 27 Oct 2023 12:00:00 +0000");
+        /// assert_eq!(repr.unix_secs, 1698408000);
+        /// assert_eq!(repr.unix_millis, 1698408000250);
+        /// assert_eq!(repr.iso_week, "2023-W43");
+        /// ```
+        pub fn format_all(&self) -> Result<TimeRepr> {
+            let datetime = self.signed_utc_datetime()?;
+            if !(0..=9999).contains(&datetime.year()) {
+                return Err(TimeError::OutOfRange(format!(
+                    "year {} cannot be formatted as RFC 2822",
+                    datetime.year()
+                )));
+            }
+            Ok(TimeRepr {
+                rfc3339: datetime.to_rfc3339(),
+                rfc2822: datetime.to_rfc2822(),
+                unix_secs: datetime.timestamp(),
+                unix_millis: datetime.timestamp_millis(),
+                iso_week: self.format_iso_week()?,
+            })
+        }
+
+        /// Recomputes the cached UTC datetime from the timestamp, validating it.
+        ///
+        /// This is useful after deserializing, which leaves the cache empty. Returns an
+        /// error if the time is before the Unix epoch or cannot be represented.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Time;
+        /// let time = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let json = serde_json::to_string(&time).unwrap();
+        /// let mut restored: Time = serde_json::from_str(&json).unwrap();
+        /// restored.normalize().unwrap();
+        /// assert_eq!(restored.format("%Y-%m-%d %H:%M:%S").unwrap(), "2023-10-27 12:00:00");
+        /// let mut before_epoch = Time::from_timestamp_signed(-60).unwrap();
+        /// assert!(before_epoch.normalize().is_err());
+        /// ```
+        pub fn normalize(&mut self) -> Result<()> {
+            self.cached_utc_datetime = None;
+            self.get_utc_datetime().map(|_| ())
+        }
+
+        /// Returns midnight UTC at the start of the week containing this time, where weeks
+        /// begin on `week_start` (e.g. `Weekday::Mon` for ISO weeks or `Weekday::Sun` in the US).
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use chrono::{DateTime, Utc, Weekday};
+        /// use time_duration_api::time_utils::Time;
+        /// let wednesday = Time::from_str("2023-10-25 15:30:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+        /// let monday = wednesday.start_of_week(Weekday::Mon).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(monday.clone()).unwrap().format("%a %Y-%m-%d %H:%M:%S").to_string(), "Mon 2023-10-23 00:00:00");
+        /// let sunday = wednesday.start_of_week(Weekday::Sun).unwrap();
+        /// assert_eq!(DateTime::<Utc>::try_from(sunday.clone()).unwrap().format("%a %Y-%m-%d %H:%M:%S").to_string(), "Sun 2023-10-22 00:00:00");
+        /// let start = monday.start_of_week(Weekday::Mon).unwrap();
+        /// assert_eq!(start, monday);
+        /// ```
+        pub fn start_of_week(&self, week_start: Weekday) -> Result<Time> {
+            let date = self.signed_utc_datetime()?.date_naive();
+            let days_back = Time::days_between_weekdays(week_start, date.weekday());
+            let start = date
+                .checked_sub_days(chrono::Days::new(days_back as u64))
+                .and_then(|date| date.and_hms_opt(0, 0, 0))
+                .ok_or(TimeError::InvalidTime)?;
+            Ok(Time::from(start.and_utc()))
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl fmt::Display for Time {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match self.timestamp.duration_since(SystemTime::UNIX_EPOCH) {
+                Ok(duration) => {
+                    let datetime = DateTime::<Utc>::from_naive_utc_and_offset(
+                        NaiveDateTime::from_timestamp_opt(
+                            duration.as_secs() as i64,
+                            duration.subsec_nanos(),
+                        )
+                        .unwrap(),
+                        Utc,
+                    );
+                    write!(f, "{}", datetime.format("%Y-%m-%d %H:%M:%S"))
+                }
+                Err(_) => write!(f, "Invalid Time"),
+            }
+        }
+    }
+
+    /// Converts a chrono `DateTime<Utc>` into a `Time`, keeping nanosecond precision.
+    ///
+    /// The result behaves exactly like an equal `Time` built any other way, so a time
+    /// before the Unix epoch is still rejected by `format` and the other methods that
+    /// require one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    /// use time_duration_api::time_utils::Time;
+    /// let dt = Utc.timestamp_opt(1698408000, 123_456_789).unwrap();
+    /// let time = Time::from(dt);
+    /// assert_eq!(DateTime::<Utc>::try_from(time).unwrap(), dt);
+    ///
+    /// let mut converted = Time::from(Utc.with_ymd_and_hms(1960, 1, 1, 0, 0, 0).unwrap());
+    /// let mut signed = Time::from_timestamp_signed(-315_619_200).unwrap();
+    /// assert_eq!(converted, signed);
+    /// assert!(converted.format("%Y").is_err() && signed.format("%Y").is_err());
+    /// assert!(converted.log_prefix().is_err() && signed.log_prefix().is_err());
+    /// ```
+    #[cfg(feature = "std")]
+    impl From<DateTime<Utc>> for Time {
+        fn from(datetime: DateTime<Utc>) -> Self {
+            // Only cache what `get_utc_datetime` would have produced, which excludes pre-epoch times
+            Time {
+                timestamp: SystemTime::from(datetime),
+                cached_utc_datetime: (datetime.timestamp() >= 0).then_some(datetime),
+            }
+        }
+    }
+
+    /// Converts a `Time` into a chrono `DateTime<Utc>`, keeping nanosecond precision.
+    ///
+    /// Returns `TimeError::InvalidTime` if the time is outside the range chrono can represent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{DateTime, TimeZone, Utc};
+    /// use std::time::{Duration, UNIX_EPOCH};
+    /// use time_duration_api::time_utils::{Time, TimeError};
+    /// let time = Time::from_timestamp_signed(-315_619_200).unwrap();
+    /// let datetime = DateTime::<Utc>::try_from(time).unwrap();
+    /// assert_eq!(datetime, Utc.with_ymd_and_hms(1960, 1, 1, 0, 0, 0).unwrap());
+    ///
+    /// let far_future = Time::from_system_time(UNIX_EPOCH + Duration::from_secs(1 << 60));
+    /// assert_eq!(DateTime::<Utc>::try_from(far_future), Err(TimeError::InvalidTime));
+    /// ```
+    #[cfg(feature = "std")]
+    impl TryFrom<Time> for DateTime<Utc> {
+        type Error = TimeError;
+
+        fn try_from(time: Time) -> Result<Self> {
+            time.signed_utc_datetime()
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl From<SystemTime> for Time {
+        fn from(timestamp: SystemTime) -> Self {
+            Time::from_system_time(timestamp)
+        }
+    }
+
+    /// Returns the Unix epoch, so types containing a Time can derive `Default`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time_duration_api::time_utils::Time;
+    /// assert_eq!(Time::default().timestamp().unwrap(), 0);
+    /// ```
+    #[cfg(feature = "std")]
+    impl Default for Time {
+        fn default() -> Self {
+            Time::from_system_time(SystemTime::UNIX_EPOCH)
+        }
+    }
+
+    /// Compares two times by the instant they represent. The cached UTC datetime is ignored.
+    #[cfg(feature = "std")]
+    impl PartialEq for Time {
+        fn eq(&self, other: &Self) -> bool {
+            self.timestamp == other.timestamp
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl Eq for Time {}
+
+    /// Orders times chronologically, so a `Vec<Time>` can be sorted with `sort()`. The cached
+    /// UTC datetime is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time_duration_api::time_utils::Time;
+    /// let at = |secs: i64| Time::from_timestamp_signed(secs).unwrap();
+    /// let mut times = vec![at(300), at(-60), at(0), at(1698408000), at(120)];
+    /// times.sort();
+    /// let secs: Vec<i64> = times.iter().map(|time| time.timestamp_signed()).collect();
+    /// assert_eq!(secs, vec![-60, 0, 120, 300, 1698408000]);
+    /// assert_eq!(times.iter().max(), Some(&at(1698408000)));
+    /// ```
+    #[cfg(feature = "std")]
+    impl Ord for Time {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.timestamp.cmp(&other.timestamp)
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl PartialOrd for Time {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct CustomDuration {
+        duration: Duration,
+    }
+
+    /// The components of a CustomDuration, each being the remainder after the larger units.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct DurationParts {
+        pub days: u64,
+        pub hours: u64,
+        pub minutes: u64,
+        pub seconds: u64,
+        pub nanos: u32,
+    }
+
+    /// How to round a duration to a coarser unit, used by `CustomDuration::round_secs_with`.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub enum RoundingMode {
+        /// Round down.
+        Floor,
+        /// Round up.
+        Ceil,
+        /// Round to the nearest value, with halves rounded up.
+        Nearest,
+    }
+
+    impl CustomDuration {
+        /// A zero-length duration.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// assert_eq!(CustomDuration::ZERO, CustomDuration::from_secs(0));
+        /// ```
+        pub const ZERO: CustomDuration = CustomDuration {
+            duration: Duration::ZERO,
+        };
+
+        /// The longest representable duration, equal to `Duration::MAX`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use core::time::Duration;
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// assert_eq!(CustomDuration::MAX.as_nanos(), Duration::MAX.as_nanos());
+        /// ```
+        pub const MAX: CustomDuration = CustomDuration {
+            duration: Duration::MAX,
+        };
+
+        /// Creates a CustomDuration from a number of seconds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_secs(60);
+        /// println!("Duration: {}", duration.format_human_readable());
+        /// ```
+        pub fn from_secs(secs: u64) -> Self {
+            CustomDuration {
+                duration: Duration::from_secs(secs),
+            }
+        }
+
+        /// Creates a CustomDuration from a number of milliseconds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_millis(1000);
+        /// println!("Duration: {}", duration.format_human_readable());
+        /// ```
+        pub fn from_millis(millis: u64) -> Self {
+            CustomDuration {
+                duration: Duration::from_millis(millis),
+            }
+        }
+
+        /// Creates a CustomDuration from a number of microseconds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_micros(1000000);
+        /// println!("Duration: {}", duration.format_human_readable());
+        /// ```
+        pub fn from_micros(micros: u64) -> Self {
+            CustomDuration {
+                duration: Duration::from_micros(micros),
+            }
+        }
+
+        /// Creates a CustomDuration from a number of nanoseconds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_nanos(1000000000);
+        /// println!("Duration: {}", duration.format_human_readable());
+        /// ```
+        pub fn from_nanos(nanos: u64) -> Self {
+            CustomDuration {
+                duration: Duration::from_nanos(nanos),
+            }
+        }
+
+        /// Creates a CustomDuration from a number of seconds given as a float.
+        ///
+        /// Returns an error if the value is NaN, infinite, negative, or too large.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_secs_f64(2.5).unwrap();
+        /// assert_eq!(duration.as_millis(), 2500);
+        /// assert!(CustomDuration::from_secs_f64(-1.0).is_err());
+        /// assert!(CustomDuration::from_secs_f64(f64::NAN).is_err());
+        /// ```
+        pub fn from_secs_f64(secs: f64) -> Result<Self> {
+            if !secs.is_finite() || secs < 0.0 {
+                return Err(TimeError::ParseError(format!(
+                    "invalid number of seconds: {}",
+                    secs
+                )));
+            }
+            Duration::try_from_secs_f64(secs)
+                .map(|duration| CustomDuration { duration })
+                .map_err(|e| TimeError::ParseError(e.to_string()))
+        }
+
+        /// Creates a CustomDuration from a human-readable string (e.g., "1h 30m").
+        ///
+        /// Returns the duration or an error if the string is invalid.
+        ///
+        /// A bare zero ("0") is accepted as a zero duration and a leading `+` is
+        /// ignored. A leading `-` is rejected, as negative durations are not supported.
+        ///
+        /// Input containing colons and no letters (e.g. "1:30:00") is parsed as a clock
+        /// duration with `from_clock` instead.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_str("2h 30m").unwrap();
+        /// println!("Duration: {}", duration.format_human_readable());
+        /// assert_eq!(CustomDuration::from_str("0s").unwrap().as_nanos(), 0);
+        /// assert_eq!(CustomDuration::from_str("0").unwrap().as_nanos(), 0);
+        /// assert_eq!(CustomDuration::from_str("+1h").unwrap().as_secs(), 3600);
+        /// assert!(CustomDuration::from_str("-1h").is_err());
+        /// assert_eq!(
+        ///     CustomDuration::from_str("1:30:00").unwrap(),
+        ///     CustomDuration::from_str("1h 30m").unwrap()
+        /// );
+        /// ```
+        #[cfg(feature = "humantime")]
+        #[allow(clippy::should_implement_trait)]
+        pub fn from_str(duration_str: &str) -> Result<Self> {
+            let trimmed = duration_str.trim();
+            if trimmed.starts_with('-') {
+                return Err(TimeError::ParseError(format!(
+                    "negative durations are not supported: '{}'",
+                    duration_str
+                )));
+            }
+            let unsigned = trimmed.strip_prefix('+').unwrap_or(trimmed);
+            if !unsigned.is_empty() && unsigned.chars().all(|c| c == '0') {
+                return Ok(CustomDuration::from_secs(0));
+            }
+            if unsigned.contains(':') && !unsigned.chars().any(|c| c.is_alphabetic()) {
+                return CustomDuration::from_clock(unsigned);
+            }
+
+            humantime::parse_duration(unsigned)
+                .map(|dur| CustomDuration { duration: dur })
+                .map_err(|e| TimeError::ParseError(e.to_string()))
+        }
+
+        /// Adds two CustomDuration instances.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the sum overflows. Use `checked_add` or `saturating_add` to avoid this.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let dur1 = CustomDuration::from_secs(60);
+        /// let dur2 = CustomDuration::from_secs(120);
+        /// let sum = dur1.add(&dur2);
+        /// println!("Sum: {}", sum.format_human_readable());
+        /// ```
+        pub fn add(&self, other: &CustomDuration) -> CustomDuration {
+            CustomDuration {
+                duration: self.duration + other.duration,
+            }
+        }
+
+        /// Adds two CustomDuration instances, returning `None` if the sum overflows.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let dur1 = CustomDuration::from_secs(60);
+        /// let dur2 = CustomDuration::from_secs(120);
+        /// assert_eq!(dur1.checked_add(&dur2), Some(CustomDuration::from_secs(180)));
+        /// let near_max = CustomDuration::from_secs(u64::MAX);
+        /// assert_eq!(near_max.checked_add(&dur1), None);
+        /// ```
+        pub fn checked_add(&self, other: &CustomDuration) -> Option<CustomDuration> {
+            self.duration
+                .checked_add(other.duration)
+                .map(|duration| CustomDuration { duration })
+        }
+
+        /// Adds two CustomDuration instances, clamping to the maximum duration on overflow.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let near_max = CustomDuration::from_secs(u64::MAX);
+        /// let sum = near_max.saturating_add(&CustomDuration::from_secs(60));
+        /// assert_eq!(sum.as_nanos(), std::time::Duration::MAX.as_nanos());
+        /// ```
+        pub fn saturating_add(&self, other: &CustomDuration) -> CustomDuration {
+            CustomDuration {
+                duration: self.duration.saturating_add(other.duration),
+            }
+        }
+
+        /// Subtracts one CustomDuration from another.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let dur1 = CustomDuration::from_secs(180);
+        /// let dur2 = CustomDuration::from_secs(60);
+        /// let diff = dur1.sub(&dur2);
+        /// println!("Difference: {}", diff.format_human_readable());
+        /// ```
+        pub fn sub(&self, other: &CustomDuration) -> CustomDuration {
+            CustomDuration {
+                duration: self.duration - other.duration,
+            }
+        }
+
+        /// Multiplies a CustomDuration by a scalar value.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the product overflows. Use `saturating_mul` to avoid this.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_secs(60);
+        /// let multiplied = duration.mul(3);
+        /// println!("Multiplied duration: {}", multiplied.format_human_readable());
+        /// ```
+        pub fn mul(&self, scalar: u32) -> CustomDuration {
+            CustomDuration {
+                duration: self.duration * scalar,
+            }
+        }
+
+        /// Multiplies a CustomDuration by a scalar value, clamping to the maximum duration
+        /// on overflow.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// assert_eq!(CustomDuration::from_secs(60).saturating_mul(3).as_secs(), 180);
+        /// let huge = CustomDuration::from_secs(u64::MAX / 2).saturating_mul(u32::MAX);
+        /// assert_eq!(huge, CustomDuration::MAX);
+        /// ```
+        pub fn saturating_mul(&self, scalar: u32) -> CustomDuration {
+            CustomDuration {
+                duration: self.duration.saturating_mul(scalar),
+            }
+        }
+
+        /// Divides a CustomDuration by a scalar value.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the divisor is zero. Use `checked_div` to avoid this.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_secs(120);
+        /// let divided = duration.div(2);
+        /// println!("Divided duration: {}", divided.format_human_readable());
+        /// ```
+        pub fn div(&self, divisor: u32) -> CustomDuration {
+            CustomDuration {
+                duration: self.duration / divisor,
+            }
+        }
+
+        /// Divides a CustomDuration by a scalar value, returning `None` if the divisor is zero.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_secs(120);
+        /// assert_eq!(duration.checked_div(2), Some(CustomDuration::from_secs(60)));
+        /// assert_eq!(duration.checked_div(0), None);
+        /// ```
+        pub fn checked_div(&self, divisor: u32) -> Option<CustomDuration> {
+            self.duration
+                .checked_div(divisor)
+                .map(|duration| CustomDuration { duration })
+        }
+
+        /// Rounds the duration down to whole seconds, discarding the fractional part.
+        ///
+        /// Use `round_secs_with` to round up or to the nearest second instead.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_millis(1500);
+        /// let rounded = duration.round_secs();
+        /// println!("Rounded duration: {}", rounded.format_human_readable());
+        /// ```
+        pub fn round_secs(&self) -> CustomDuration {
+            CustomDuration {
+                duration: Duration::from_secs(self.duration.as_secs()),
+            }
+        }
+
+        /// Rounds the duration to whole seconds using the given rounding mode.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, RoundingMode};
+        /// let round = |millis, mode| CustomDuration::from_millis(millis).round_secs_with(mode).as_secs();
+        /// assert_eq!(round(1400, RoundingMode::Floor), 1);
+        /// assert_eq!(round(1500, RoundingMode::Floor), 1);
+        /// assert_eq!(round(1600, RoundingMode::Floor), 1);
+        /// assert_eq!(round(1400, RoundingMode::Ceil), 2);
+        /// assert_eq!(round(1500, RoundingMode::Ceil), 2);
+        /// assert_eq!(round(1600, RoundingMode::Ceil), 2);
+        /// assert_eq!(round(1400, RoundingMode::Nearest), 1);
+        /// assert_eq!(round(1500, RoundingMode::Nearest), 2);
+        /// assert_eq!(round(1600, RoundingMode::Nearest), 2);
+        /// ```
+        pub fn round_secs_with(&self, mode: RoundingMode) -> CustomDuration {
+            let second = CustomDuration::from_secs(1);
+            match mode {
+                RoundingMode::Floor => self.floor_to(&second),
+                RoundingMode::Ceil => self.ceil_to(&second),
+                RoundingMode::Nearest => self.round_to_nearest(&second),
+            }
+        }
+
+        /// Returns the duration as a number of seconds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_secs(60);
+        /// println!("Seconds: {}", duration.as_secs());
+        /// ```
+        pub fn as_secs(&self) -> u64 {
+            self.duration.as_secs()
+        }
+
+        /// Returns the duration as a number of seconds, including the fractional part.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_millis(2500);
+        /// assert_eq!(duration.as_secs_f64(), 2.5);
+        /// ```
+        pub fn as_secs_f64(&self) -> f64 {
+            self.duration.as_secs_f64()
+        }
+
+        /// Returns the duration as a number of milliseconds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_millis(1000);
+        /// println!("Milliseconds: {}", duration.as_millis());
+        /// ```
+        pub fn as_millis(&self) -> u128 {
+            self.duration.as_millis()
+        }
+
+        /// Returns the duration as a number of microseconds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_micros(1000000);
+        /// println!("Microseconds: {}", duration.as_micros());
+        /// ```
+        pub fn as_micros(&self) -> u128 {
+            self.duration.as_micros()
+        }
+
+        /// Returns the duration as a number of nanoseconds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_nanos(1000000000);
+        /// println!("Nanoseconds: {}", duration.as_nanos());
+        /// ```
+        pub fn as_nanos(&self) -> u128 {
+            self.duration.as_nanos()
+        }
+
+        /// Formats the duration into a human-readable string.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_secs(3661);
+        /// println!("Human readable: {}", duration.format_human_readable());
+        /// ```
+        #[cfg(feature = "humantime")]
+        pub fn format_human_readable(&self) -> String {
+            humantime::format_duration(self.duration).to_string()
+        }
+
+        /// Formats the duration into a human-readable string with at most `max_units` components.
+        ///
+        /// Only the most significant components are kept. The dropped remainder is rounded
+        /// half-up into the last kept component, carrying into larger units where needed.
+        /// A `max_units` of zero is treated as one.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_nanos(3_661_003_400_000);
+        /// assert_eq!(duration.format_human_readable(), "1h 1m 1s 3ms 400us");
+        /// assert_eq!(duration.format_human_readable_rounded(2), "1h 1m");
+        /// assert_eq!(duration.format_human_readable_rounded(1), "1h");
+        /// assert_eq!(CustomDuration::from_secs(0).format_human_readable_rounded(2), "0s");
+        ///
+        /// // 1h 59m 59s rounds up and carries into the hours.
+        /// let almost_two_hours = CustomDuration::from_secs(2 * 3600 - 1);
+        /// assert_eq!(almost_two_hours.format_human_readable_rounded(1), "2h");
+        /// assert_eq!(almost_two_hours.format_human_readable_rounded(2), "2h");
+        /// assert_eq!(CustomDuration::from_secs(90).format_human_readable_rounded(1), "2m");
+        /// assert_eq!(CustomDuration::from_secs(89).format_human_readable_rounded(1), "1m");
+        /// ```
+        #[cfg(feature = "humantime")]
+        pub fn format_human_readable_rounded(&self, max_units: usize) -> String {
+            let formatted = self.format_human_readable();
+            let components: Vec<&str> = formatted.split_whitespace().collect();
+            let max_units = max_units.max(1);
+            if components.len() <= max_units {
+                return formatted;
+            }
+            let mut kept = 0u128;
+            let mut last_unit = 0u128;
+            for component in &components[..max_units] {
+                let split = component
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(component.len());
+                let (value, unit) = component.split_at(split);
+                last_unit = CustomDuration::humantime_unit_nanos(unit);
+                kept += value.parse::<u128>().unwrap_or(0) * last_unit;
+            }
+            let remainder = self.duration.as_nanos().saturating_sub(kept);
+            if remainder * 2 >= last_unit {
+                kept += last_unit;
+            }
+            CustomDuration::from_nanos_u128(kept)
+                .format_human_readable()
+                .split_whitespace()
+                .take(max_units)
+                .collect::<Vec<_>>()
+                .join(" ")
+        }
+
+        // Helper function to get the length of a unit as printed by humantime, in nanoseconds
+        #[cfg(feature = "humantime")]
+        fn humantime_unit_nanos(unit: &str) -> u128 {
+            const SECOND: u128 = 1_000_000_000;
+            match unit {
+                "year" | "years" => 31_557_600 * SECOND,
+                "month" | "months" => 2_630_016 * SECOND,
+                "day" | "days" => 86_400 * SECOND,
+                "h" => 3_600 * SECOND,
+                "m" => 60 * SECOND,
+                "s" => SECOND,
+                "ms" => 1_000_000,
+                "us" => 1_000,
+                _ => 1,
+            }
+        }
+
+        /// Returns the number of whole ticks in the duration at the given tick rate.
+        ///
+        /// A tick rate of zero yields zero ticks.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_millis(1500);
+        /// assert_eq!(duration.to_ticks(60), 90);
+        /// assert_eq!(duration.to_ticks(0), 0);
+        /// ```
+        pub fn to_ticks(&self, tick_rate_hz: u32) -> u64 {
+            let ticks = self.duration.as_nanos() * tick_rate_hz as u128 / 1_000_000_000;
+            ticks.min(u64::MAX as u128) as u64
+        }
+
+        /// Creates a CustomDuration spanning the given number of ticks at the given tick rate.
+        ///
+        /// Partial nanoseconds are rounded up so that converting back with `to_ticks`
+        /// yields the original tick count. A tick rate of zero yields a zero duration.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_ticks(90, 60);
+        /// assert_eq!(duration.as_millis(), 1500);
+        /// for ticks in [1, 59, 60, 61, 3600] {
+        ///     assert_eq!(CustomDuration::from_ticks(ticks, 60).to_ticks(60), ticks);
+        /// }
+        /// ```
+        pub fn from_ticks(ticks: u64, tick_rate_hz: u32) -> CustomDuration {
+            if tick_rate_hz == 0 {
+                return CustomDuration::from_nanos(0);
+            }
+            let nanos = (ticks as u128 * 1_000_000_000).div_ceil(tick_rate_hz as u128);
+            CustomDuration::from_nanos_u128(nanos)
+        }
+
+        // Helper function to build a CustomDuration from a u128 nanosecond count, saturating at the maximum
+        fn from_nanos_u128(nanos: u128) -> CustomDuration {
+            let secs = nanos / 1_000_000_000;
+            if secs > u64::MAX as u128 {
+                return CustomDuration {
+                    duration: Duration::MAX,
+                };
+            }
+            CustomDuration {
+                duration: Duration::new(secs as u64, (nanos % 1_000_000_000) as u32),
+            }
+        }
+
+        /// Returns whether the duration exceeds the baseline by more than the allowed percentage.
+        ///
+        /// Returns an error if `allowed_pct` is NaN, infinite, or negative.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let baseline = CustomDuration::from_millis(1000);
+        /// assert!(!CustomDuration::from_millis(1050).regressed(&baseline, 10.0).unwrap());
+        /// assert!(CustomDuration::from_millis(1150).regressed(&baseline, 10.0).unwrap());
+        /// assert!(CustomDuration::from_millis(1150).regressed(&baseline, f64::NAN).is_err());
+        /// assert!(CustomDuration::from_millis(1150).regressed(&baseline, -5.0).is_err());
+        /// ```
+        pub fn regressed(&self, baseline: &CustomDuration, allowed_pct: f64) -> Result<bool> {
+            if !allowed_pct.is_finite() || allowed_pct < 0.0 {
+                return Err(TimeError::ParseError(format!(
+                    "invalid allowed percentage: {}",
+                    allowed_pct
+                )));
+            }
+            let limit = baseline.duration.as_nanos() as f64 * (1.0 + allowed_pct / 100.0);
+            Ok(self.duration.as_nanos() as f64 > limit)
+        }
+
+        /// Splits the duration into days, hours, minutes, seconds, and nanoseconds.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, DurationParts};
+        /// let parts = CustomDuration::from_secs(90061).split();
+        /// assert_eq!(
+        ///     parts,
+        ///     DurationParts { days: 1, hours: 1, minutes: 1, seconds: 1, nanos: 0 }
+        /// );
+        /// ```
+        pub fn split(&self) -> DurationParts {
+            let secs = self.duration.as_secs();
+            DurationParts {
+                days: secs / 86400,
+                hours: secs % 86400 / 3600,
+                minutes: secs % 3600 / 60,
+                seconds: secs % 60,
+                nanos: self.duration.subsec_nanos(),
+            }
+        }
+
+        /// Formats the duration compactly (e.g. "1h30m15s"), dropping the smallest units
+        /// until the result fits within `max_len` characters.
+        ///
+        /// The dropped units are rounded half-up into the last kept unit. If even the largest
+        /// unit does not fit, it is returned on its own.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let duration = CustomDuration::from_secs(5415);
+        /// assert_eq!(duration.format_fit(8), "1h30m15s");
+        /// assert_eq!(duration.format_fit(7), "1h30m");
+        /// assert_eq!(duration.format_fit(5), "1h30m");
+        /// assert_eq!(duration.format_fit(4), "2h");
+        /// assert_eq!(duration.format_fit(1), "2h");
+        ///
+        /// // 1h29m45s: the dropped 45s rounds the minutes up, the dropped 29m rounds the hour down.
+        /// let duration = CustomDuration::from_secs(5385);
+        /// assert_eq!(duration.format_fit(7), "1h30m");
+        /// assert_eq!(duration.format_fit(4), "1h");
+        /// ```
+        pub fn format_fit(&self, max_len: usize) -> String {
+            const UNITS: [u64; 7] = [
+                86_400_000_000_000,
+                3_600_000_000_000,
+                60_000_000_000,
+                1_000_000_000,
+                1_000_000,
+                1_000,
+                1,
+            ];
+            let full = self.compact_components().concat();
+            if full.len() <= max_len {
+                return full;
+            }
+            // Never round past the largest nonzero unit, so it is kept on its own at worst
+            let nanos = self.duration.as_nanos();
+            let largest = UNITS
+                .iter()
+                .position(|unit| nanos >= *unit as u128)
+                .unwrap_or(UNITS.len() - 1);
+            let mut formatted = full;
+            for unit in UNITS[largest..].iter().rev() {
+                formatted = self
+                    .round_to_nearest(&CustomDuration::from_nanos(*unit))
+                    .compact_components()
+                    .concat();
+                if formatted.len() <= max_len {
+                    break;
+                }
+            }
+            formatted
+        }
+
+        // Helper function to list the nonzero units of the duration, largest first
+        fn compact_components(&self) -> Vec<String> {
+            let secs = self.duration.as_secs();
+            let nanos = self.duration.subsec_nanos() as u64;
+            let units = [
+                (secs / 86400, "d"),
+                (secs % 86400 / 3600, "h"),
+                (secs % 3600 / 60, "m"),
+                (secs % 60, "s"),
+                (nanos / 1_000_000, "ms"),
+                (nanos % 1_000_000 / 1000, "us"),
+                (nanos % 1000, "ns"),
+            ];
+            let components: Vec<String> = units
+                .iter()
+                .filter(|(value, _)| *value > 0)
+                .map(|(value, unit)| format!("{}{}", value, unit))
+                .collect();
+            if components.is_empty() {
+                vec!["0s".to_string()]
+            } else {
+                components
+            }
+        }
+
+        /// Rounds the duration to the nearest multiple of the granularity, rounding halves up.
+        ///
+        /// A zero granularity returns the duration unchanged.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let worked = CustomDuration::from_secs(67 * 60); // 1h07m
+        /// let quarter = CustomDuration::from_secs(15 * 60);
+        /// let hour = CustomDuration::from_secs(3600);
+        /// assert_eq!(worked.round_to_nearest(&quarter), CustomDuration::from_secs(60 * 60));
+        /// assert_eq!(worked.round_to_nearest(&hour), CustomDuration::from_secs(3600));
+        /// let long = CustomDuration::from_secs(90 * 60);
+        /// assert_eq!(long.round_to_nearest(&hour), CustomDuration::from_secs(2 * 3600));
+        /// ```
+        pub fn round_to_nearest(&self, granularity: &CustomDuration) -> CustomDuration {
+            let step = granularity.duration.as_nanos();
+            if step == 0 {
+                return *self;
+            }
+            let nanos = self.duration.as_nanos();
+            let floor = nanos - nanos % step;
+            if nanos % step >= step - step / 2 {
+                CustomDuration::from_nanos_u128(floor + step)
+            } else {
+                CustomDuration::from_nanos_u128(floor)
+            }
+        }
+
+        /// Rounds the duration up to a multiple of the granularity.
+        ///
+        /// A zero granularity returns the duration unchanged.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let worked = CustomDuration::from_secs(67 * 60); // 1h07m
+        /// let quarter = CustomDuration::from_secs(15 * 60);
+        /// let hour = CustomDuration::from_secs(3600);
+        /// assert_eq!(worked.ceil_to(&quarter), CustomDuration::from_secs(75 * 60));
+        /// assert_eq!(worked.ceil_to(&hour), CustomDuration::from_secs(2 * 3600));
+        /// assert_eq!(hour.ceil_to(&quarter), hour);
+        ///
+        /// let minute = CustomDuration::from_secs(60);
+        /// assert_eq!(CustomDuration::from_secs(70).ceil_to(&minute).as_secs(), 120);
+        /// assert_eq!(CustomDuration::from_secs(70).ceil_to(&CustomDuration::from_secs(0)).as_secs(), 70);
+        /// ```
+        pub fn ceil_to(&self, granularity: &CustomDuration) -> CustomDuration {
+            let step = granularity.duration.as_nanos();
+            if step == 0 {
+                return *self;
+            }
+            CustomDuration::from_nanos_u128(self.duration.as_nanos().div_ceil(step) * step)
+        }
+
+        /// Rounds the duration down to a multiple of the granularity.
+        ///
+        /// A zero granularity returns the duration unchanged.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let worked = CustomDuration::from_secs(67 * 60); // 1h07m
+        /// let quarter = CustomDuration::from_secs(15 * 60);
+        /// let hour = CustomDuration::from_secs(3600);
+        /// assert_eq!(worked.floor_to(&quarter), CustomDuration::from_secs(60 * 60));
+        /// assert_eq!(worked.floor_to(&hour), CustomDuration::from_secs(3600));
+        ///
+        /// let minute = CustomDuration::from_secs(60);
+        /// assert_eq!(CustomDuration::from_secs(70).floor_to(&minute).as_secs(), 60);
+        /// assert_eq!(CustomDuration::from_secs(59).floor_to(&minute).as_secs(), 0);
+        /// ```
+        pub fn floor_to(&self, granularity: &CustomDuration) -> CustomDuration {
+            let step = granularity.duration.as_nanos();
+            if step == 0 {
+                return *self;
+            }
+            let nanos = self.duration.as_nanos();
+            CustomDuration::from_nanos_u128(nanos - nanos % step)
+        }
+
+        /// Creates a CustomDuration from loosely formatted human input (e.g. "1.5h", "90 min", "2d4h").
+        ///
+        /// The input is a sequence of quantities, each followed by a unit. Quantities may be
+        /// decimal and whitespace between parts is optional. The accepted units are
+        /// `s`/`sec`/`secs`, `m`/`min`/`mins`, `h`/`hr`/`hrs`, and `d`/`day`/`days`, in any case.
+        ///
+        /// Returns a parse error for unknown units, missing quantities, or missing units.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// assert_eq!(CustomDuration::parse_loose("1.5h").unwrap().as_secs(), 5400);
+        /// assert_eq!(CustomDuration::parse_loose("90 min").unwrap().as_secs(), 5400);
+        /// assert_eq!(CustomDuration::parse_loose("2d4h").unwrap().as_secs(), 187200);
+        /// assert_eq!(CustomDuration::parse_loose("1 hr 30 mins").unwrap().as_secs(), 5400);
+        /// assert_eq!(CustomDuration::parse_loose("2 days 45secs").unwrap().as_secs(), 172845);
+        /// assert_eq!(CustomDuration::parse_loose("0.25 m").unwrap().as_secs(), 15);
+        /// assert!(CustomDuration::parse_loose("3 weeks").is_err());
+        /// assert!(CustomDuration::parse_loose("90").is_err());
+        /// ```
+        pub fn parse_loose(duration_str: &str) -> Result<Self> {
+            let mut rest = duration_str.trim();
+            if rest.is_empty() {
+                return Err(TimeError::ParseError("empty duration string".to_string()));
+            }
+
+            let mut total: u128 = 0;
+            while !rest.is_empty() {
+                let number_len = rest
+                    .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+                    .unwrap_or(rest.len());
+                let (number, after) = rest.split_at(number_len);
+                let after = after.trim_start();
+                let unit_len = after
+                    .find(|c: char| !c.is_ascii_alphabetic())
+                    .unwrap_or(after.len());
+                let (unit, after) = after.split_at(unit_len);
+
+                let unit_nanos = CustomDuration::unit_nanos(unit).ok_or_else(|| {
+                    TimeError::ParseError(format!(
+                        "unknown unit '{}' in duration '{}'",
+                        unit, duration_str
+                    ))
+                })?;
+                let nanos = CustomDuration::scale_decimal(number, unit_nanos).ok_or_else(|| {
+                    TimeError::ParseError(format!(
+                        "invalid quantity '{}' in duration '{}'",
+                        number, duration_str
+                    ))
+                })?;
+                total = total.checked_add(nanos).ok_or_else(|| {
+                    TimeError::ParseError(format!("duration '{}' is too large", duration_str))
+                })?;
+                rest = after.trim_start();
+            }
+
+            if total / 1_000_000_000 > u64::MAX as u128 {
+                return Err(TimeError::ParseError(format!(
+                    "duration '{}' is too large",
+                    duration_str
+                )));
+            }
+            Ok(CustomDuration::from_nanos_u128(total))
+        }
+
+        // Helper function to get the length in nanoseconds of a loosely named unit
+        fn unit_nanos(unit: &str) -> Option<u128> {
+            match unit.to_ascii_lowercase().as_str() {
+                "s" | "sec" | "secs" => Some(1_000_000_000),
+                "m" | "min" | "mins" => Some(60_000_000_000),
+                "h" | "hr" | "hrs" => Some(3_600_000_000_000),
+                "d" | "day" | "days" => Some(86_400_000_000_000),
+                _ => None,
+            }
+        }
+
+        // Helper function to multiply a decimal string like "1.5" by a unit without floating point
+        fn scale_decimal(number: &str, unit_nanos: u128) -> Option<u128> {
+            let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+            if whole.is_empty() && fraction.is_empty() {
+                return None;
+            }
+            let whole: u128 = match whole {
+                "" => 0,
+                digits => digits.parse().ok()?,
+            };
+            let mut nanos = whole.checked_mul(unit_nanos)?;
+            // Digits beyond nanosecond precision of a day cannot affect the result
+            let fraction = &fraction[..fraction.len().min(18)];
+            if !fraction.is_empty() {
+                let numerator: u128 = fraction.parse().ok()?;
+                let denominator = 10u128.pow(fraction.len() as u32);
+                nanos = nanos.checked_add(numerator * unit_nanos / denominator)?;
+            }
+            Some(nanos)
+        }
+
+        /// Multiplies the duration by a factor, then rounds the result to the nearest
+        /// multiple of the granularity.
+        ///
+        /// Returns an error if the factor is NaN, infinite, negative, or the result is too large.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let estimate = CustomDuration::from_secs(45 * 60);
+        /// let quarter = CustomDuration::from_secs(15 * 60);
+        /// let doubled = estimate.scale_rounded(2.0, &quarter).unwrap();
+        /// assert_eq!(doubled, CustomDuration::from_secs(90 * 60));
+        /// let padded = estimate.scale_rounded(1.1, &quarter).unwrap();
+        /// assert_eq!(padded, CustomDuration::from_secs(45 * 60));
+        /// assert!(estimate.scale_rounded(-1.0, &quarter).is_err());
+        /// ```
+        pub fn scale_rounded(&self, factor: f64, granularity: &CustomDuration) -> Result<Self> {
+            if !factor.is_finite() || factor < 0.0 {
+                return Err(TimeError::ParseError(format!(
+                    "invalid scale factor: {}",
+                    factor
+                )));
+            }
+            let scaled = CustomDuration::from_secs_f64(self.as_secs_f64() * factor)?;
+            Ok(scaled.round_to_nearest(granularity))
+        }
+
+        /// Returns the frequency in hertz of an event recurring with this duration as its period.
+        ///
+        /// A zero duration returns `f64::INFINITY`. Use `as_frequency_hz` to get `None` instead.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// assert_eq!(CustomDuration::from_millis(250).to_frequency_hz(), 4.0);
+        /// assert_eq!(CustomDuration::from_millis(0).to_frequency_hz(), f64::INFINITY);
+        /// ```
+        pub fn to_frequency_hz(&self) -> f64 {
+            if self.duration.is_zero() {
+                return f64::INFINITY;
+            }
+            1.0 / self.as_secs_f64()
+        }
+
+        /// Returns the frequency in hertz of an event recurring with this duration as its period,
+        /// or `None` for a zero duration. Otherwise the same as `to_frequency_hz`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// assert_eq!(CustomDuration::from_millis(20).as_frequency_hz(), Some(50.0));
+        /// assert_eq!(CustomDuration::from_millis(0).as_frequency_hz(), None);
+        /// ```
+        pub fn as_frequency_hz(&self) -> Option<f64> {
+            if self.duration.is_zero() {
+                return None;
+            }
+            Some(self.to_frequency_hz())
+        }
+
+        /// Creates a CustomDuration for the period of the given frequency in hertz.
+        ///
+        /// Returns an error if the frequency is not a positive, finite number.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let period = CustomDuration::from_frequency_hz(4.0).unwrap();
+        /// assert_eq!(period, CustomDuration::from_millis(250));
+        /// let period = CustomDuration::from_frequency_hz(50.0).unwrap();
+        /// assert_eq!(period, CustomDuration::from_millis(20));
+        /// assert!(CustomDuration::from_frequency_hz(0.0).is_err());
+        /// assert!(CustomDuration::from_frequency_hz(f64::INFINITY).is_err());
+        /// ```
+        pub fn from_frequency_hz(hz: f64) -> Result<Self> {
+            if !hz.is_finite() || hz <= 0.0 {
+                return Err(TimeError::ParseError(format!(
+                    "invalid frequency: {} Hz",
+                    hz
+                )));
+            }
+            CustomDuration::from_secs_f64(1.0 / hz)
+        }
+
+        /// Creates a CustomDuration from a clock-style string, either `H:MM:SS` or `M:SS`.
+        ///
+        /// The seconds may have a fractional part (e.g. "1:02:03.5"). Minutes and seconds
+        /// following a larger field must be below 60.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// assert_eq!(CustomDuration::from_clock("1:2:3").unwrap().as_secs(), 3723);
+        /// assert_eq!(CustomDuration::from_clock("05:30").unwrap().as_secs(), 330);
+        /// assert_eq!(CustomDuration::from_clock("0:00:01.5").unwrap().as_millis(), 1500);
+        /// assert!(CustomDuration::from_clock("1:75:00").is_err());
+        /// assert!(CustomDuration::from_clock("1:2:3:4").is_err());
+        /// ```
+        pub fn from_clock(clock_str: &str) -> Result<Self> {
+            let invalid =
+                || TimeError::ParseError(format!("invalid clock duration: '{}'", clock_str));
+            let fields: Vec<&str> = clock_str.trim().split(':').collect();
+            let (hours, minutes, seconds) = match fields.as_slice() {
+                [hours, minutes, seconds] => (*hours, *minutes, *seconds),
+                [minutes, seconds] => ("0", *minutes, *seconds),
+                _ => return Err(invalid()),
+            };
+            let whole = |field: &str| -> Option<u128> {
+                if field.is_empty() || !field.bytes().all(|b| b.is_ascii_digit()) {
+                    return None;
+                }
+                field.parse().ok()
+            };
+
+            let hours = whole(hours).ok_or_else(invalid)?;
+            let minutes = whole(minutes).ok_or_else(invalid)?;
+            let seconds =
+                CustomDuration::scale_decimal(seconds, 1_000_000_000).ok_or_else(invalid)?;
+            if (fields.len() == 3 && minutes >= 60) || seconds >= 60_000_000_000 {
+                return Err(invalid());
+            }
+
+            let total = hours
+                .checked_mul(3_600_000_000_000)
+                .and_then(|nanos| nanos.checked_add(minutes.checked_mul(60_000_000_000)?))
+                .and_then(|nanos| nanos.checked_add(seconds))
+                .filter(|nanos| nanos / 1_000_000_000 <= u64::MAX as u128)
+                .ok_or_else(invalid)?;
+            Ok(CustomDuration::from_nanos_u128(total))
+        }
+
+        /// Merges runs of consecutive samples shorter than `min_keep` into their sum, keeping
+        /// samples of at least `min_keep` separate.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let ms = CustomDuration::from_millis;
+        /// let samples = [ms(2), ms(3), ms(1), ms(50), ms(4), ms(4)];
+        /// let coalesced = CustomDuration::coalesce(&samples, ms(10));
+        /// assert_eq!(coalesced, vec![ms(6), ms(50), ms(8)]);
+        /// ```
+        pub fn coalesce(
+            samples: &[CustomDuration],
+            min_keep: CustomDuration,
+        ) -> Vec<CustomDuration> {
+            let mut coalesced = Vec::new();
+            let mut pending: Option<CustomDuration> = None;
+            for sample in samples {
+                if *sample < min_keep {
+                    pending = Some(match pending {
+                        Some(total) => total.saturating_add(sample),
+                        None => *sample,
+                    });
+                } else {
+                    coalesced.extend(pending.take());
+                    coalesced.push(*sample);
+                }
+            }
+            coalesced.extend(pending);
+            coalesced
+        }
+
+        /// Restricts the duration to the range `[min, max]`.
+        ///
+        /// In debug builds this panics if `min` is greater than `max`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let min = CustomDuration::from_secs(1);
+        /// let max = CustomDuration::from_secs(30);
+        /// assert_eq!(CustomDuration::from_millis(200).clamp(&min, &max), min);
+        /// assert_eq!(CustomDuration::from_secs(10).clamp(&min, &max), CustomDuration::from_secs(10));
+        /// assert_eq!(CustomDuration::from_secs(90).clamp(&min, &max), max);
+        /// ```
+        pub fn clamp(&self, min: &CustomDuration, max: &CustomDuration) -> CustomDuration {
+            debug_assert!(min <= max, "clamp requires min <= max");
+            if self < min {
+                *min
+            } else if self > max {
+                *max
+            } else {
+                *self
+            }
+        }
+
+        /// Parses a duration range such as `"30s-2m"` into its lower and upper bounds.
+        ///
+        /// Both endpoints accept the same syntax as `from_str`, and whitespace around the `-`
+        /// separator is ignored. Returns a parse error if either endpoint is invalid, and an
+        /// `OutOfRange` error if the lower bound is greater than the upper bound.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let (min, max) = CustomDuration::parse_range("30s-2m").unwrap();
+        /// assert_eq!(min.as_secs(), 30);
+        /// assert_eq!(max.as_secs(), 120);
+        /// assert_eq!(CustomDuration::parse_range("1h - 1h").unwrap().1.as_secs(), 3600);
+        /// assert!(CustomDuration::parse_range("2m-30s").is_err());
+        /// assert!(CustomDuration::parse_range("30s").is_err());
+        /// ```
+        #[cfg(feature = "humantime")]
+        pub fn parse_range(range_str: &str) -> Result<(Self, Self)> {
+            let (start, end) = range_str.split_once('-').ok_or_else(|| {
+                TimeError::ParseError(format!("missing '-' in duration range '{}'", range_str))
+            })?;
+            let min = CustomDuration::from_str(start)?;
+            let max = CustomDuration::from_str(end)?;
+            if min > max {
+                return Err(TimeError::OutOfRange(format!(
+                    "lower bound {} exceeds upper bound {} in '{}'",
+                    min, max, range_str
+                )));
+            }
+            Ok((min, max))
+        }
+
+        /// Formats the duration as speech-friendly text for text-to-speech output,
+        /// e.g. "two minutes and thirty seconds".
+        ///
+        /// Days, hours, minutes, seconds, and milliseconds are included when nonzero. Numbers
+        /// below one hundred are spelled out as words and larger ones are written as digits.
+        /// Units are separated by commas, with "and" before the last one.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// assert_eq!(CustomDuration::from_secs(150).to_spoken(), "two minutes and thirty seconds");
+        /// assert_eq!(
+        ///     CustomDuration::from_secs(3661).to_spoken(),
+        ///     "one hour, one minute and one second"
+        /// );
+        /// assert_eq!(CustomDuration::from_secs(86400 * 365).to_spoken(), "365 days");
+        /// assert_eq!(CustomDuration::from_millis(45).to_spoken(), "forty-five milliseconds");
+        /// assert_eq!(CustomDuration::from_secs(0).to_spoken(), "zero seconds");
+        /// ```
+        pub fn to_spoken(&self) -> String {
+            let parts = self.split();
+            let units = [
+                (parts.days, "day"),
+                (parts.hours, "hour"),
+                (parts.minutes, "minute"),
+                (parts.seconds, "second"),
+                ((parts.nanos / 1_000_000) as u64, "millisecond"),
+            ];
+            let mut spoken: Vec<String> = units
+                .iter()
+                .filter(|(value, _)| *value > 0)
+                .map(|(value, unit)| {
+                    let plural = if *value == 1 { "" } else { "s" };
+                    format!(
+                        "{} {}{}",
+                        CustomDuration::spoken_number(*value),
+                        unit,
+                        plural
+                    )
+                })
+                .collect();
+
+            match spoken.pop() {
+                None => "zero seconds".to_string(),
+                Some(last) if spoken.is_empty() => last,
+                Some(last) => format!("{} and {}", spoken.join(", "), last),
+            }
+        }
+
+        // Helper function to spell out numbers below one hundred, falling back to digits
+        fn spoken_number(value: u64) -> String {
+            const ONES: [&str; 20] = [
+                "zero",
+                "one",
+                "two",
+                "three",
+                "four",
+                "five",
+                "six",
+                "seven",
+                "eight",
+                "nine",
+                "ten",
+                "eleven",
+                "twelve",
+                "thirteen",
+                "fourteen",
+                "fifteen",
+                "sixteen",
+                "seventeen",
+                "eighteen",
+                "nineteen",
+            ];
+            const TENS: [&str; 10] = [
+                "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty",
+                "ninety",
+            ];
+            match value {
+                0..=19 => ONES[value as usize].to_string(),
+                20..=99 => match (TENS[value as usize / 10], value as usize % 10) {
+                    (tens, 0) => tens.to_string(),
+                    (tens, ones) => format!("{}-{}", tens, ONES[ones]),
+                },
+                _ => value.to_string(),
+            }
+        }
+
+        /// Returns the greatest common divisor of two durations at nanosecond precision.
+        ///
+        /// The gcd of a duration and zero is the duration itself.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let six = CustomDuration::from_secs(6);
+        /// let eight = CustomDuration::from_secs(8);
+        /// assert_eq!(six.gcd(&eight), CustomDuration::from_secs(2));
+        /// assert_eq!(six.gcd(&CustomDuration::from_secs(0)), six);
+        /// ```
+        pub fn gcd(&self, other: &CustomDuration) -> CustomDuration {
+            let (mut a, mut b) = (self.duration.as_nanos(), other.duration.as_nanos());
+            while b != 0 {
+                (a, b) = (b, a % b);
+            }
+            CustomDuration::from_nanos_u128(a)
+        }
+
+        /// Returns the least common multiple of two durations at nanosecond precision.
+        ///
+        /// The result is zero if either duration is zero, and saturates at the maximum
+        /// duration if it would overflow.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use core::time::Duration;
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let six = CustomDuration::from_secs(6);
+        /// let eight = CustomDuration::from_secs(8);
+        /// assert_eq!(six.lcm(&eight), CustomDuration::from_secs(24));
+        /// assert_eq!(six.lcm(&CustomDuration::from_secs(0)).as_nanos(), 0);
+        /// let big = CustomDuration::from_secs(u64::MAX);
+        /// let odd = CustomDuration::from_nanos(7);
+        /// assert_eq!(big.lcm(&odd).as_nanos(), Duration::MAX.as_nanos());
+        /// ```
+        pub fn lcm(&self, other: &CustomDuration) -> CustomDuration {
+            let gcd = self.gcd(other).duration.as_nanos();
+            if gcd == 0 {
+                return CustomDuration::from_secs(0);
+            }
+            let lcm = (self.duration.as_nanos() / gcd).checked_mul(other.duration.as_nanos());
+            CustomDuration::from_nanos_u128(lcm.unwrap_or(u128::MAX))
+        }
+
+        /// Parses a duration with `from_str`, falling back to zero if the string is invalid.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// assert_eq!(CustomDuration::parse_or_zero("1h 30m").as_secs(), 5400);
+        /// assert_eq!(CustomDuration::parse_or_zero("garbage").as_nanos(), 0);
+        /// ```
+        #[cfg(feature = "humantime")]
+        pub fn parse_or_zero(duration_str: &str) -> CustomDuration {
+            CustomDuration::from_str(duration_str).unwrap_or(CustomDuration::from_secs(0))
+        }
+
+        /// Formats the duration as `HH:MM:SS.sss` (e.g. "01:30:45.500").
+        ///
+        /// Hours are not wrapped at 24, and the fraction is truncated to milliseconds. The
+        /// result can be read back with `from_hms_string`.
         ///
         /// # Example
         ///
         /// ```
         /// use time_duration_api::time_utils::CustomDuration;
-        /// let dur1 = CustomDuration::from_secs(60);
-        /// let dur2 = CustomDuration::from_secs(120);
-        /// let sum = dur1.add(&dur2);
-        /// println!("Sum: {}", sum.format_human_readable());
+        /// assert_eq!(CustomDuration::from_millis(5_445_500).as_hms_string(), "01:30:45.500");
+        /// assert_eq!(CustomDuration::from_secs(0).as_hms_string(), "00:00:00.000");
+        /// assert_eq!(CustomDuration::from_secs(100 * 3600).as_hms_string(), "100:00:00.000");
         /// ```
-        pub fn add(&self, other: &CustomDuration) -> CustomDuration {
-            CustomDuration {
-                duration: self.duration + other.duration,
+        pub fn as_hms_string(&self) -> String {
+            let secs = self.duration.as_secs();
+            format!(
+                "{:02}:{:02}:{:02}.{:03}",
+                secs / 3600,
+                secs % 3600 / 60,
+                secs % 60,
+                self.duration.subsec_millis()
+            )
+        }
+
+        /// Creates a CustomDuration from an `HH:MM:SS` string with optional fractional seconds,
+        /// as produced by `as_hms_string`.
+        ///
+        /// Returns a parse error if any field is missing or if the minutes or seconds are not
+        /// below 60.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// for millis in [0, 1, 5_445_500, 86_399_999, 360_000_000] {
+        ///     let duration = CustomDuration::from_millis(millis);
+        ///     assert_eq!(CustomDuration::from_hms_string(&duration.as_hms_string()).unwrap(), duration);
+        /// }
+        /// assert_eq!(CustomDuration::from_hms_string("00:00:07").unwrap().as_secs(), 7);
+        /// assert!(CustomDuration::from_hms_string("01:75:00").is_err());
+        /// assert!(CustomDuration::from_hms_string("01:00:60").is_err());
+        /// assert!(CustomDuration::from_hms_string("30:00").is_err());
+        /// ```
+        pub fn from_hms_string(hms_str: &str) -> Result<Self> {
+            if hms_str.matches(':').count() != 2 {
+                return Err(TimeError::ParseError(format!(
+                    "expected HH:MM:SS, got '{}'",
+                    hms_str
+                )));
             }
+            CustomDuration::from_clock(hms_str)
         }
 
-        /// Subtracts one CustomDuration from another.
+        /// Returns the shorter of two durations.
         ///
         /// # Example
         ///
         /// ```
         /// use time_duration_api::time_utils::CustomDuration;
-        /// let dur1 = CustomDuration::from_secs(180);
-        /// let dur2 = CustomDuration::from_secs(60);
-        /// let diff = dur1.sub(&dur2);
-        /// println!("Difference: {}", diff.format_human_readable());
+        /// let short = CustomDuration::from_secs(5);
+        /// let long = CustomDuration::from_secs(30);
+        /// assert_eq!(short.min(&long), short);
+        /// assert_eq!(long.min(&short), short);
         /// ```
-        pub fn sub(&self, other: &CustomDuration) -> CustomDuration {
-            CustomDuration {
-                duration: self.duration - other.duration,
+        pub fn min(&self, other: &CustomDuration) -> CustomDuration {
+            if other < self {
+                *other
+            } else {
+                *self
             }
         }
 
-        /// Multiplies a CustomDuration by a scalar value.
+        /// Returns the longer of two durations.
         ///
         /// # Example
         ///
         /// ```
         /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_secs(60);
-        /// let multiplied = duration.mul(3);
-        /// println!("Multiplied duration: {}", multiplied.format_human_readable());
+        /// let short = CustomDuration::from_secs(5);
+        /// let long = CustomDuration::from_secs(30);
+        /// assert_eq!(short.max(&long), long);
+        /// assert_eq!(long.max(&short), long);
+        /// assert_eq!(short.max(&CustomDuration::MAX), CustomDuration::MAX);
         /// ```
-        pub fn mul(&self, scalar: u32) -> CustomDuration {
-            CustomDuration {
-                duration: self.duration * scalar,
+        pub fn max(&self, other: &CustomDuration) -> CustomDuration {
+            if other > self {
+                *other
+            } else {
+                *self
             }
         }
 
-        /// Divides a CustomDuration by a scalar value.
+        /// Returns this duration as a percentage of `whole`, or `None` if `whole` is zero.
         ///
         /// # Example
         ///
         /// ```
         /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_secs(120);
-        /// let divided = duration.div(2);
-        /// println!("Divided duration: {}", divided.format_human_readable());
+        /// let elapsed = CustomDuration::from_secs(15);
+        /// assert_eq!(elapsed.percentage_of(&CustomDuration::from_secs(60)), Some(25.0));
+        /// assert_eq!(elapsed.percentage_of(&CustomDuration::from_secs(0)), None);
         /// ```
-        pub fn div(&self, divisor: u32) -> CustomDuration {
-            CustomDuration {
-                duration: self.duration / divisor,
+        pub fn percentage_of(&self, whole: &CustomDuration) -> Option<f64> {
+            if whole.duration.is_zero() {
+                return None;
             }
+            Some(self.as_secs_f64() / whole.as_secs_f64() * 100.0)
         }
-        /// Rounds the duration to the nearest second.
+
+        /// Formats the duration compactly with at most two units and no spaces (e.g. "1d2h").
+        ///
+        /// Durations of a second or more use the `d`, `h`, `m`, and `s` units, skipping zero
+        /// components and dropping anything below a second. Shorter durations are shown in
+        /// whole milliseconds, or as "0s" below one millisecond.
         ///
         /// # Example
         ///
         /// ```
         /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_millis(1500);
-        /// let rounded = duration.round_secs();
-        /// println!("Rounded duration: {}", rounded.format_human_readable());
+        /// assert_eq!(CustomDuration::from_secs(93_784).humanize_short(), "1d2h");
+        /// assert_eq!(CustomDuration::from_secs(86_405).humanize_short(), "1d5s");
+        /// assert_eq!(CustomDuration::from_millis(45_250).humanize_short(), "45s");
+        /// assert_eq!(CustomDuration::from_millis(500).humanize_short(), "500ms");
+        /// assert_eq!(CustomDuration::from_nanos(900).humanize_short(), "0s");
         /// ```
-        pub fn round_secs(&self) -> CustomDuration {
-            CustomDuration {
-                duration: Duration::from_secs(self.duration.as_secs()),
+        pub fn humanize_short(&self) -> String {
+            let parts = self.split();
+            let units = [
+                (parts.days, "d"),
+                (parts.hours, "h"),
+                (parts.minutes, "m"),
+                (parts.seconds, "s"),
+            ];
+            let short: String = units
+                .iter()
+                .filter(|(value, _)| *value > 0)
+                .take(2)
+                .map(|(value, unit)| format!("{}{}", value, unit))
+                .collect();
+            match (short.is_empty(), self.duration.subsec_millis()) {
+                (false, _) => short,
+                (true, 0) => "0s".to_string(),
+                (true, millis) => format!("{}ms", millis),
             }
         }
 
-        /// Returns the duration as a number of seconds.
+        /// Returns the absolute difference between two durations, in either order.
         ///
         /// # Example
         ///
         /// ```
         /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_secs(60);
-        /// println!("Seconds: {}", duration.as_secs());
+        /// let a = CustomDuration::from_millis(1200);
+        /// let b = CustomDuration::from_millis(1150);
+        /// assert_eq!(a.abs_diff(&b), CustomDuration::from_millis(50));
+        /// assert_eq!(b.abs_diff(&a), CustomDuration::from_millis(50));
+        /// assert!(a.abs_diff(&b) <= CustomDuration::from_millis(100));
         /// ```
-        pub fn as_secs(&self) -> u64 {
-            self.duration.as_secs()
+        pub fn abs_diff(&self, other: &CustomDuration) -> CustomDuration {
+            let duration = if self >= other {
+                self.duration - other.duration
+            } else {
+                other.duration - self.duration
+            };
+            CustomDuration { duration }
         }
 
-        /// Returns the duration as a number of milliseconds.
+        /// Splits the duration into parts proportional to the weights, at nanosecond precision.
+        ///
+        /// The rounding remainder goes to the last part, so the parts always sum exactly to the
+        /// whole. If every weight is zero, the last part receives the whole duration. Returns
+        /// an empty vector for empty weights.
         ///
         /// # Example
         ///
         /// ```
         /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_millis(1000);
-        /// println!("Milliseconds: {}", duration.as_millis());
+        /// let budget = CustomDuration::from_secs(60);
+        /// let parts = budget.split_weighted(&[1, 2, 3]);
+        /// let secs: Vec<u64> = parts.iter().map(|part| part.as_secs()).collect();
+        /// assert_eq!(secs, vec![10, 20, 30]);
+        /// let uneven = CustomDuration::from_nanos(10).split_weighted(&[1, 1, 1]);
+        /// let nanos: Vec<u128> = uneven.iter().map(|part| part.as_nanos()).collect();
+        /// assert_eq!(nanos, vec![3, 3, 4]);
+        /// assert_eq!(uneven.iter().sum::<CustomDuration>().as_nanos(), 10);
+        /// assert!(budget.split_weighted(&[]).is_empty());
         /// ```
-        pub fn as_millis(&self) -> u128 {
-            self.duration.as_millis()
+        pub fn split_weighted(&self, weights: &[u32]) -> Vec<CustomDuration> {
+            let whole = self.duration.as_nanos();
+            let total_weight: u128 = weights.iter().map(|weight| *weight as u128).sum();
+            let mut parts: Vec<CustomDuration> = weights
+                .iter()
+                .map(|weight| match total_weight {
+                    0 => 0,
+                    total => whole * *weight as u128 / total,
+                })
+                .map(CustomDuration::from_nanos_u128)
+                .collect();
+            let assigned: u128 = parts.iter().map(|part| part.duration.as_nanos()).sum();
+            if let Some(last) = parts.last_mut() {
+                *last =
+                    CustomDuration::from_nanos_u128(last.duration.as_nanos() + whole - assigned);
+            }
+            parts
         }
 
-        /// Returns the duration as a number of microseconds.
+        /// Returns true if the duration is zero.
         ///
         /// # Example
         ///
         /// ```
         /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_micros(1000000);
-        /// println!("Microseconds: {}", duration.as_micros());
+        /// assert!(CustomDuration::from_secs(0).is_zero());
+        /// assert!(!CustomDuration::from_nanos(1).is_zero());
         /// ```
-        pub fn as_micros(&self) -> u128 {
-            self.duration.as_micros()
+        pub fn is_zero(&self) -> bool {
+            self.duration.is_zero()
         }
 
-        /// Returns the duration as a number of nanoseconds.
+        /// Converts the duration to a `chrono::Duration` for use in chrono date arithmetic.
+        ///
+        /// Returns an `InvalidTime` error if the duration is too long for chrono.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, TimeError};
+        /// let hour = CustomDuration::from_secs(3600).to_chrono_duration().unwrap();
+        /// assert_eq!(hour, chrono::Duration::hours(1));
+        /// assert_eq!(CustomDuration::MAX.to_chrono_duration(), Err(TimeError::InvalidTime));
+        /// ```
+        #[cfg(feature = "std")]
+        pub fn to_chrono_duration(&self) -> Result<chrono::Duration> {
+            chrono::Duration::from_std(self.duration).map_err(|_| TimeError::InvalidTime)
+        }
+
+        /// Creates a CustomDuration from a `chrono::Duration`.
+        ///
+        /// Returns an `InvalidTime` error if the duration is negative.
         ///
         /// # Example
         ///
         /// ```
         /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_nanos(1000000000);
-        /// println!("Nanoseconds: {}", duration.as_nanos());
+        /// let duration = CustomDuration::from_chrono_duration(chrono::Duration::minutes(90)).unwrap();
+        /// assert_eq!(duration.as_secs(), 5400);
+        /// assert!(CustomDuration::from_chrono_duration(chrono::Duration::seconds(-1)).is_err());
         /// ```
-        pub fn as_nanos(&self) -> u128 {
-            self.duration.as_nanos()
+        #[cfg(feature = "std")]
+        pub fn from_chrono_duration(duration: chrono::Duration) -> Result<Self> {
+            duration
+                .to_std()
+                .map(|duration| CustomDuration { duration })
+                .map_err(|_| TimeError::InvalidTime)
         }
 
-        /// Formats the duration into a human-readable string.
+        /// Creates a CustomDuration from days, hours, minutes, seconds, and nanoseconds.
+        ///
+        /// The components are summed, so they may exceed their usual ranges (e.g. 90 minutes).
+        /// Returns a parse error if the total overflows.
         ///
         /// # Example
         ///
         /// ```
         /// use time_duration_api::time_utils::CustomDuration;
-        /// let duration = CustomDuration::from_secs(3661);
-        /// println!("Human readable: {}", duration.format_human_readable());
+        /// let duration = CustomDuration::from_components(1, 2, 3, 4, 0).unwrap();
+        /// assert_eq!(duration.as_secs(), 86400 + 2 * 3600 + 3 * 60 + 4);
+        /// let with_nanos = CustomDuration::from_components(0, 0, 90, 0, 1_500_000_000).unwrap();
+        /// assert_eq!(with_nanos.as_millis(), 5_401_500);
+        /// assert!(CustomDuration::from_components(u64::MAX, 0, 0, 0, 0).is_err());
         /// ```
-        pub fn format_human_readable(&self) -> String {
-            humantime::format_duration(self.duration).to_string()
+        pub fn from_components(
+            days: u64,
+            hours: u64,
+            minutes: u64,
+            seconds: u64,
+            nanos: u32,
+        ) -> Result<Self> {
+            let secs = [(days, 86400), (hours, 3600), (minutes, 60), (seconds, 1)]
+                .iter()
+                .try_fold(0u64, |total, (value, unit)| {
+                    value.checked_mul(*unit)?.checked_add(total)
+                })
+                .ok_or_else(|| TimeError::ParseError("duration components overflow".to_string()))?;
+            Duration::from_secs(secs)
+                .checked_add(Duration::from_nanos(nanos as u64))
+                .map(|duration| CustomDuration { duration })
+                .ok_or_else(|| TimeError::ParseError("duration components overflow".to_string()))
+        }
+
+        /// Builds an exponential backoff schedule of `attempts` delays, where the delay for
+        /// attempt `n` (starting at 0) is `base * factor^n`.
+        ///
+        /// Each delay is capped at `cap` if given. Delays that would overflow saturate at
+        /// `cap`, or at `CustomDuration::MAX` without a cap. Returns an error if `factor` is
+        /// NaN, infinite, or less than 1.0.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let ms = CustomDuration::from_millis;
+        /// let schedule = CustomDuration::exponential_backoff(ms(100), 2.0, 4, None).unwrap();
+        /// assert_eq!(schedule, vec![ms(100), ms(200), ms(400), ms(800)]);
+        /// let capped = CustomDuration::exponential_backoff(ms(100), 2.0, 5, Some(ms(500))).unwrap();
+        /// assert_eq!(capped, vec![ms(100), ms(200), ms(400), ms(500), ms(500)]);
+        /// let huge = CustomDuration::exponential_backoff(CustomDuration::MAX, 10.0, 2, None).unwrap();
+        /// assert_eq!(huge, vec![CustomDuration::MAX, CustomDuration::MAX]);
+        ///
+        /// assert!(CustomDuration::exponential_backoff(ms(100), 0.5, 3, None).is_err());
+        /// assert!(CustomDuration::exponential_backoff(ms(100), -2.0, 3, None).is_err());
+        /// assert!(CustomDuration::exponential_backoff(ms(100), f64::NAN, 3, None).is_err());
+        /// assert!(CustomDuration::exponential_backoff(ms(100), f64::INFINITY, 3, None).is_err());
+        /// ```
+        pub fn exponential_backoff(
+            base: CustomDuration,
+            factor: f64,
+            attempts: usize,
+            cap: Option<CustomDuration>,
+        ) -> Result<Vec<CustomDuration>> {
+            if !factor.is_finite() || factor < 1.0 {
+                return Err(TimeError::ParseError(format!(
+                    "invalid backoff factor: {}",
+                    factor
+                )));
+            }
+            let limit = cap.unwrap_or(CustomDuration::MAX);
+            let mut secs = base.as_secs_f64();
+            let mut schedule = Vec::with_capacity(attempts);
+            for _ in 0..attempts {
+                let delay = CustomDuration::from_secs_f64(secs).unwrap_or(limit);
+                schedule.push(delay.min(&limit));
+                secs *= factor;
+            }
+            Ok(schedule)
+        }
+
+        /// Returns the duration's components as a map with the keys `"days"`, `"hours"`,
+        /// `"minutes"`, `"seconds"`, and `"millis"`, for use in templates.
+        ///
+        /// Each value is the remainder after the larger units, and every key is present even
+        /// when its value is zero.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let map = CustomDuration::from_secs(90061).as_component_map();
+        /// assert_eq!(map.len(), 5);
+        /// assert_eq!(map["days"], 1);
+        /// assert_eq!(map["hours"], 1);
+        /// assert_eq!(map["minutes"], 1);
+        /// assert_eq!(map["seconds"], 1);
+        /// assert_eq!(map["millis"], 0);
+        /// ```
+        #[cfg(feature = "std")]
+        pub fn as_component_map(&self) -> HashMap<&'static str, u64> {
+            let parts = self.split();
+            HashMap::from([
+                ("days", parts.days),
+                ("hours", parts.hours),
+                ("minutes", parts.minutes),
+                ("seconds", parts.seconds),
+                ("millis", (parts.nanos / 1_000_000) as u64),
+            ])
+        }
+
+        /// Creates a CustomDuration from a decimal number with a unit suffix, allowing
+        /// scientific notation (e.g. "1.5e3ms" or "2.5s").
+        ///
+        /// The supported units are `ns`, `us` (or `µs`), `ms`, and `s`. Returns a parse error
+        /// for a missing or unknown unit, an invalid number, or a negative value.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// assert_eq!(CustomDuration::from_scientific("1.5e3ms").unwrap(), CustomDuration::from_millis(1500));
+        /// assert_eq!(CustomDuration::from_scientific("2.5s").unwrap(), CustomDuration::from_millis(2500));
+        /// assert_eq!(CustomDuration::from_scientific("4E2 us").unwrap(), CustomDuration::from_nanos(400_000));
+        /// assert!(CustomDuration::from_scientific("1.5e3").is_err());
+        /// assert!(CustomDuration::from_scientific("-2s").is_err());
+        /// ```
+        pub fn from_scientific(duration_str: &str) -> Result<Self> {
+            let trimmed = duration_str.trim();
+            let units = [
+                ("ns", 1e-9),
+                ("us", 1e-6),
+                ("µs", 1e-6),
+                ("ms", 1e-3),
+                ("s", 1.0),
+            ];
+            let (number, secs_per_unit) = units
+                .iter()
+                .find_map(|(unit, scale)| Some((trimmed.strip_suffix(unit)?, *scale)))
+                .ok_or_else(|| {
+                    TimeError::ParseError(format!("missing unit in duration '{}'", duration_str))
+                })?;
+            let value: f64 = number.trim().parse().map_err(|_| {
+                TimeError::ParseError(format!("invalid number in duration '{}'", duration_str))
+            })?;
+            CustomDuration::from_secs_f64(value * secs_per_unit)
+        }
+
+        /// Returns true if two durations differ by at most `tolerance`.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::CustomDuration;
+        /// let ms = CustomDuration::from_millis;
+        /// assert!(ms(1000).approx_eq(&ms(1008), &ms(10)));
+        /// assert!(ms(1010).approx_eq(&ms(1000), &ms(10)));
+        /// assert!(!ms(1000).approx_eq(&ms(1011), &ms(10)));
+        /// ```
+        pub fn approx_eq(&self, other: &CustomDuration, tolerance: &CustomDuration) -> bool {
+            self.abs_diff(other) <= *tolerance
         }
     }
 
+    /// Adds two CustomDuration instances with the `+` operator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sum overflows. Use `checked_add` or `saturating_add` to avoid this.
     impl Add for CustomDuration {
         type Output = Self;
 
@@ -521,12 +4114,139 @@ pub mod time_utils {
         }
     }
 
+    /// Returns a zero-length duration, so types containing a CustomDuration can derive `Default`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time_duration_api::time_utils::CustomDuration;
+    /// assert!(CustomDuration::default().is_zero());
+    /// let parsed: Option<CustomDuration> = None;
+    /// assert_eq!(parsed.unwrap_or_default(), CustomDuration::ZERO);
+    /// ```
+    impl Default for CustomDuration {
+        fn default() -> Self {
+            CustomDuration::ZERO
+        }
+    }
+
+    /// Sums CustomDuration values, saturating at the maximum duration instead of panicking
+    /// on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time_duration_api::time_utils::CustomDuration;
+    /// let runtimes = vec![
+    ///     CustomDuration::from_secs(30),
+    ///     CustomDuration::from_secs(90),
+    ///     CustomDuration::from_millis(500),
+    /// ];
+    /// let total: CustomDuration = runtimes.iter().sum();
+    /// assert_eq!(total, CustomDuration::from_millis(120_500));
+    /// let total: CustomDuration = runtimes.into_iter().sum();
+    /// assert_eq!(total, CustomDuration::from_millis(120_500));
+    /// ```
+    impl Sum for CustomDuration {
+        fn sum<I: Iterator<Item = CustomDuration>>(iter: I) -> Self {
+            sum_saturating(iter)
+        }
+    }
+
+    impl<'a> Sum<&'a CustomDuration> for CustomDuration {
+        fn sum<I: Iterator<Item = &'a CustomDuration>>(iter: I) -> Self {
+            sum_saturating(iter.copied())
+        }
+    }
+
+    /// Collects CustomDuration values into their total, saturating at the maximum duration
+    /// instead of panicking on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time_duration_api::time_utils::CustomDuration;
+    /// let samples = vec![
+    ///     CustomDuration::from_millis(250),
+    ///     CustomDuration::from_millis(500),
+    ///     CustomDuration::from_secs(1),
+    /// ];
+    /// let total: CustomDuration = samples.into_iter().collect();
+    /// assert_eq!(total, CustomDuration::from_millis(1750));
+    /// let saturated: CustomDuration = vec![CustomDuration::MAX, CustomDuration::from_secs(1)]
+    ///     .into_iter()
+    ///     .collect();
+    /// assert_eq!(saturated, CustomDuration::MAX);
+    /// ```
+    impl FromIterator<CustomDuration> for CustomDuration {
+        fn from_iter<I: IntoIterator<Item = CustomDuration>>(iter: I) -> Self {
+            sum_saturating(iter)
+        }
+    }
+
+    /// Sums durations, saturating at the maximum duration instead of panicking on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use core::time::Duration;
+    /// use time_duration_api::time_utils::{sum_saturating, CustomDuration};
+    /// let total = sum_saturating(vec![CustomDuration::from_secs(30), CustomDuration::from_secs(90)]);
+    /// assert_eq!(total.as_secs(), 120);
+    /// let near_max = CustomDuration::from_secs(Duration::MAX.as_secs() - 1);
+    /// let saturated = sum_saturating(vec![near_max, near_max, CustomDuration::from_secs(5)]);
+    /// assert_eq!(saturated.as_nanos(), Duration::MAX.as_nanos());
+    /// assert_eq!(sum_saturating(Vec::new()).as_nanos(), 0);
+    /// ```
+    pub fn sum_saturating(durations: impl IntoIterator<Item = CustomDuration>) -> CustomDuration {
+        durations
+            .into_iter()
+            .fold(CustomDuration::from_secs(0), |total, duration| {
+                total.saturating_add(&duration)
+            })
+    }
+
+    /// Formats the duration in human-readable form. A precision (e.g. `{:.2}`) limits the
+    /// output to that many of the most significant units, as in `format_human_readable_rounded`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time_duration_api::time_utils::CustomDuration;
+    /// let duration = CustomDuration::from_nanos(3_661_003_400_000);
+    /// assert_eq!(format!("{}", duration), "1h 1m 1s 3ms 400us");
+    /// assert_eq!(format!("{:.1}", duration), "1h");
+    /// assert_eq!(format!("{:.2}", duration), "1h 1m");
+    /// ```
+    #[cfg(feature = "humantime")]
     impl fmt::Display for CustomDuration {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-            write!(f, "{}", humantime::format_duration(self.duration))
+            match f.precision() {
+                Some(max_units) => f.write_str(&self.format_human_readable_rounded(max_units)),
+                None => write!(f, "{}", humantime::format_duration(self.duration)),
+            }
         }
     }
 
+    /// Parses a CustomDuration from a human-readable string, so `.parse()` works.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time_duration_api::time_utils::CustomDuration;
+    /// let duration = "2h 30m".parse::<CustomDuration>().unwrap();
+    /// assert_eq!(duration.as_secs(), 9000);
+    /// ```
+    #[cfg(feature = "humantime")]
+    impl FromStr for CustomDuration {
+        type Err = TimeError;
+
+        fn from_str(s: &str) -> Result<Self> {
+            CustomDuration::from_str(s)
+        }
+    }
+
+    #[cfg(feature = "std")]
      impl FromStr for Time {
         type Err = TimeError;
     
@@ -563,15 +4283,12 @@ pub mod time_utils {
 
             
              for format in  formats_with_tz {
-                match DateTime::parse_from_str(s, format) {
-                    Ok(dt) => {
+                if let Ok(dt) = DateTime::parse_from_str(s, format) {
                       return Ok(Time {
                          timestamp: SystemTime::from(dt),
                          cached_utc_datetime: None,
                        });
-                     }
-                Err(_)=> {}
-           }
+                }
     
         }
     
@@ -580,4 +4297,462 @@ pub mod time_utils {
     }
     
     }
+
+    /// A span of time between a start and an end (inclusive).
+    #[cfg(feature = "std")]
+    #[derive(Debug, Clone)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct TimeInterval {
+        start: Time,
+        end: Time,
+    }
+
+    #[cfg(feature = "std")]
+    impl TimeInterval {
+        /// Creates a TimeInterval from a start and an end time.
+        ///
+        /// Returns an error if the end is before the start.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::{CustomDuration, Time, TimeInterval};
+        /// let start = Time::now();
+        /// let end = start.add_duration(&CustomDuration::from_secs(3600));
+        /// assert!(TimeInterval::new(start.clone(), end.clone()).is_ok());
+        /// assert!(TimeInterval::new(end, start).is_err());
+        /// ```
+        pub fn new(start: Time, end: Time) -> Result<Self> {
+            if end.timestamp < start.timestamp {
+                return Err(TimeError::InvalidTime);
+            }
+            Ok(TimeInterval { start, end })
+        }
+
+        /// Returns the start of the interval.
+        pub fn start(&self) -> &Time {
+            &self.start
+        }
+
+        /// Returns the end of the interval.
+        pub fn end(&self) -> &Time {
+            &self.end
+        }
+    }
+
+    /// Sorts the intervals by start and merges overlapping or adjacent ones in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time_duration_api::time_utils::{merge_intervals, CustomDuration, Time, TimeInterval};
+    /// let base = Time::from_str("2023-10-27 00:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+    /// let at = |hours: u64| base.add_duration(&CustomDuration::from_secs(hours * 3600));
+    /// let mut intervals = vec![
+    ///     TimeInterval::new(at(8), at(9)).unwrap(),  // disjoint
+    ///     TimeInterval::new(at(2), at(4)).unwrap(),  // adjacent to 0-2
+    ///     TimeInterval::new(at(0), at(2)).unwrap(),
+    ///     TimeInterval::new(at(5), at(7)).unwrap(),
+    ///     TimeInterval::new(at(6), at(8)).unwrap(),  // overlaps 5-7 and touches 8-9
+    ///     TimeInterval::new(at(11), at(12)).unwrap(), // disjoint
+    /// ];
+    /// merge_intervals(&mut intervals);
+    /// let hours: Vec<(u64, u64)> = intervals
+    ///     .iter()
+    ///     .map(|i| {
+    ///         let start = i.start().timestamp().unwrap() - base.timestamp().unwrap();
+    ///         let end = i.end().timestamp().unwrap() - base.timestamp().unwrap();
+    ///         (start / 3600, end / 3600)
+    ///     })
+    ///     .collect();
+    /// assert_eq!(hours, vec![(0, 4), (5, 9), (11, 12)]);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn merge_intervals(intervals: &mut Vec<TimeInterval>) {
+        intervals.sort_by_key(|interval| interval.start.timestamp);
+
+        let mut merged: Vec<TimeInterval> = Vec::with_capacity(intervals.len());
+        for interval in intervals.drain(..) {
+            match merged.last_mut() {
+                Some(last) if interval.start.timestamp <= last.end.timestamp => {
+                    if interval.end.timestamp > last.end.timestamp {
+                        last.end = interval.end;
+                    }
+                }
+                _ => merged.push(interval),
+            }
+        }
+        *intervals = merged;
+    }
+
+    /// A source of the current wall-clock time.
+    ///
+    /// `Time::now_with` and `Time::elapsed_with` read the time from a Clock, so tests can
+    /// substitute a `FixedClock` for the real `SystemClock`.
+    #[cfg(feature = "std")]
+    pub trait Clock: Send + Sync {
+        /// Returns the current time.
+        fn now(&self) -> SystemTime;
+    }
+
+    /// A Clock that reads the system time. This is what `Time::now` uses.
+    #[cfg(feature = "std")]
+    #[derive(Debug, Clone, Copy, Default)]
+    pub struct SystemClock;
+
+    #[cfg(feature = "std")]
+    impl Clock for SystemClock {
+        fn now(&self) -> SystemTime {
+            SystemTime::now()
+        }
+    }
+
+    /// A Clock that always returns the same time, for deterministic tests.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time_duration_api::time_utils::{CustomDuration, FixedClock, Time};
+    /// let start = Time::from_str("2023-10-27 12:00:00+00:00", "%Y-%m-%d %H:%M:%S%:z").unwrap();
+    /// let clock = FixedClock::new(&start.add_duration(&CustomDuration::from_secs(90)));
+    /// assert_eq!(start.elapsed_with(&clock).unwrap().as_secs(), 90);
+    /// assert_eq!(Time::now_with(&clock).timestamp().unwrap(), 1698408090);
+    /// ```
+    #[cfg(feature = "std")]
+    #[derive(Debug, Clone, Copy)]
+    pub struct FixedClock {
+        time: SystemTime,
+    }
+
+    #[cfg(feature = "std")]
+    impl FixedClock {
+        /// Creates a FixedClock that always returns the given time.
+        pub fn new(time: &Time) -> Self {
+            FixedClock {
+                time: time.as_system_time(),
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    impl Clock for FixedClock {
+        fn now(&self) -> SystemTime {
+            self.time
+        }
+    }
+
+    /// A monotonic stopwatch for measuring elapsed intervals.
+    ///
+    /// Unlike `Time`, which follows the wall clock, a Stopwatch is backed by
+    /// `std::time::Instant` and is unaffected by system clock adjustments.
+    #[cfg(feature = "std")]
+    #[derive(Debug, Clone, Copy)]
+    pub struct Stopwatch {
+        started_at: Instant,
+    }
+
+    #[cfg(feature = "std")]
+    impl Stopwatch {
+        /// Starts a new stopwatch.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use time_duration_api::time_utils::Stopwatch;
+        /// let stopwatch = Stopwatch::start();
+        /// println!("Elapsed: {}", stopwatch.elapsed());
+        /// ```
+        pub fn start() -> Self {
+            Stopwatch {
+                started_at: Instant::now(),
+            }
+        }
+
+        /// Returns the time elapsed since the stopwatch was started or last restarted.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use std::{thread, time::Duration};
+        /// use time_duration_api::time_utils::{CustomDuration, Stopwatch};
+        /// let stopwatch = Stopwatch::start();
+        /// thread::sleep(Duration::from_millis(20));
+        /// let first = stopwatch.elapsed();
+        /// assert!(first >= CustomDuration::from_millis(20));
+        /// assert!(stopwatch.elapsed() >= first);
+        /// ```
+        pub fn elapsed(&self) -> CustomDuration {
+            CustomDuration {
+                duration: self.started_at.elapsed(),
+            }
+        }
+
+        /// Restarts the stopwatch, returning the time elapsed before the restart.
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use std::{thread, time::Duration};
+        /// use time_duration_api::time_utils::{CustomDuration, Stopwatch};
+        /// let mut stopwatch = Stopwatch::start();
+        /// thread::sleep(Duration::from_millis(50));
+        /// let lap = stopwatch.restart();
+        /// assert!(lap >= CustomDuration::from_millis(50));
+        /// assert!(stopwatch.elapsed() < lap);
+        /// ```
+        pub fn restart(&mut self) -> CustomDuration {
+            let now = Instant::now();
+            let elapsed = now.duration_since(self.started_at);
+            self.started_at = now;
+            CustomDuration { duration: elapsed }
+        }
+    }
+
+    /// Returns the version of the IANA timezone database used for named timezones (e.g. "2025b").
+    ///
+    /// Requires the `tz` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time_duration_api::time_utils::tz_version;
+    /// assert!(!tz_version().is_empty());
+    /// ```
+    #[cfg(feature = "tz")]
+    pub fn tz_version() -> &'static str {
+        chrono_tz::IANA_TZDB_VERSION
+    }
+
+    /// Parses a UTC offset such as "+05:30" and returns it in seconds east of UTC.
+    ///
+    /// Returns an `InvalidTimezoneFormat` error if the offset cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use time_duration_api::time_utils::{parse_offset_seconds, TimeError};
+    /// assert_eq!(parse_offset_seconds("+05:30").unwrap(), 19800);
+    /// assert_eq!(parse_offset_seconds("-08:00").unwrap(), -28800);
+    /// assert_eq!(
+    ///     parse_offset_seconds("IST"),
+    ///     Err(TimeError::InvalidTimezoneFormat("IST".to_string()))
+    /// );
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_offset_seconds(timezone: &str) -> Result<i32> {
+        parse_fixed_offset(timezone).map(|offset| offset.local_minus_utc())
+    }
+
+    // Helper function to parse a fixed UTC offset such as "+05:30"
+    #[cfg(feature = "std")]
+    fn parse_fixed_offset(timezone: &str) -> Result<FixedOffset> {
+        timezone
+            .parse()
+            .map_err(|_| TimeError::InvalidTimezoneFormat(timezone.to_string()))
+    }
+
+    /// Serializes a CustomDuration as a number of seconds, for use with `#[serde(with = "...")]`.
+    ///
+    /// Whole durations are written as integers and others as fractional seconds. Deserializing
+    /// accepts either form.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::{Deserialize, Serialize};
+    /// use time_duration_api::time_utils::{serde_secs, CustomDuration};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Config {
+    ///     #[serde(with = "serde_secs")]
+    ///     timeout: CustomDuration,
+    /// }
+    ///
+    /// let config = Config { timeout: CustomDuration::from_secs(90) };
+    /// let json = serde_json::to_string(&config).unwrap();
+    /// assert_eq!(json, r#"{"timeout":90}"#);
+    /// let parsed: Config = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(parsed.timeout, config.timeout);
+    /// let fractional: Config = serde_json::from_str(r#"{"timeout":1.5}"#).unwrap();
+    /// assert_eq!(fractional.timeout, CustomDuration::from_millis(1500));
+    /// assert!(serde_json::from_str::<Config>(r#"{"timeout":-1}"#).is_err());
+    ///
+    /// // Whole seconds beyond f64 precision survive a round trip.
+    /// let large = Config { timeout: CustomDuration::from_secs((1 << 53) + 1) };
+    /// let json = serde_json::to_string(&large).unwrap();
+    /// let parsed: Config = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(parsed.timeout, large.timeout);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub mod serde_secs {
+        use super::CustomDuration;
+        use core::fmt;
+        use serde::{
+            de::{Error, Visitor},
+            Deserializer, Serializer,
+        };
+
+        /// Serializes the duration as seconds.
+        pub fn serialize<S: Serializer>(
+            duration: &CustomDuration,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            if duration.split().nanos == 0 {
+                serializer.serialize_u64(duration.as_secs())
+            } else {
+                serializer.serialize_f64(duration.as_secs_f64())
+            }
+        }
+
+        /// Deserializes the duration from seconds.
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<CustomDuration, D::Error> {
+            // Self-describing formats pass fractional input to `visit_f64` despite the hint
+            deserializer.deserialize_u64(SecsVisitor)
+        }
+
+        // Visitor that reads whole seconds as integers and only falls back to f64 for fractions
+        struct SecsVisitor;
+
+        impl Visitor<'_> for SecsVisitor {
+            type Value = CustomDuration;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a non-negative number of seconds")
+            }
+
+            fn visit_u64<E: Error>(self, secs: u64) -> Result<CustomDuration, E> {
+                Ok(CustomDuration::from_secs(secs))
+            }
+
+            fn visit_i64<E: Error>(self, secs: i64) -> Result<CustomDuration, E> {
+                u64::try_from(secs)
+                    .map(CustomDuration::from_secs)
+                    .map_err(|_| E::custom(format_args!("invalid number of seconds: {}", secs)))
+            }
+
+            fn visit_f64<E: Error>(self, secs: f64) -> Result<CustomDuration, E> {
+                CustomDuration::from_secs_f64(secs).map_err(E::custom)
+            }
+        }
+    }
+
+    /// Serializes a CustomDuration as a human-readable string like "1h 30m", for use with
+    /// `#[serde(with = "...")]`.
+    ///
+    /// Deserializing accepts anything `CustomDuration::from_str` does.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::{Deserialize, Serialize};
+    /// use time_duration_api::time_utils::{serde_humantime, CustomDuration};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Config {
+    ///     #[serde(with = "serde_humantime")]
+    ///     timeout: CustomDuration,
+    /// }
+    ///
+    /// let config = Config { timeout: CustomDuration::from_secs(5400) };
+    /// let json = serde_json::to_string(&config).unwrap();
+    /// assert_eq!(json, r#"{"timeout":"1h 30m"}"#);
+    /// let parsed: Config = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(parsed.timeout, config.timeout);
+    /// assert!(serde_json::from_str::<Config>(r#"{"timeout":"soon"}"#).is_err());
+    /// ```
+    #[cfg(all(feature = "serde", feature = "humantime"))]
+    pub mod serde_humantime {
+        use super::CustomDuration;
+        use alloc::string::String;
+        use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+        /// Serializes the duration as a human-readable string.
+        pub fn serialize<S: Serializer>(
+            duration: &CustomDuration,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&duration.format_human_readable())
+        }
+
+        /// Deserializes the duration from a human-readable string.
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<CustomDuration, D::Error> {
+            let text = String::deserialize(deserializer)?;
+            CustomDuration::from_str(&text).map_err(D::Error::custom)
+        }
+    }
+
+    /// Serializes a Time as an object with both an `epoch_millis` number and an RFC 3339 `iso`
+    /// string, for use with `#[serde(with = "...")]`.
+    ///
+    /// Deserializing uses `epoch_millis` when present and falls back to `iso` otherwise.
+    /// Times before the Unix epoch have a negative `epoch_millis`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use serde::{Deserialize, Serialize};
+    /// use time_duration_api::time_utils::{serde_epoch_iso, Time};
+    ///
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Event {
+    ///     #[serde(with = "serde_epoch_iso")]
+    ///     at: Time,
+    /// }
+    ///
+    /// let at = Time::from_str("2023-10-27 12:00:00.250+00:00", "%Y-%m-%d %H:%M:%S%.f%:z").unwrap();
+    /// let json = serde_json::to_string(&Event { at }).unwrap();
+    /// assert_eq!(
+    ///     json,
+    ///     r#"{"at":{"epoch_millis":1698408000250,"iso":"2023-10-27T12:00:00.250Z"}}"#
+    /// );
+    /// let parsed: Event = serde_json::from_str(&json).unwrap();
+    /// assert_eq!(parsed.at.timestamp_millis().unwrap(), 1698408000250);
+    /// let iso_only: Event = serde_json::from_str(r#"{"at":{"iso":"2023-10-27T14:00:00+02:00"}}"#).unwrap();
+    /// assert_eq!(iso_only.at.timestamp().unwrap(), 1698408000);
+    /// assert!(serde_json::from_str::<Event>(r#"{"at":{}}"#).is_err());
+    /// ```
+    #[cfg(all(feature = "serde", feature = "std"))]
+    pub mod serde_epoch_iso {
+        use super::{Time, TimeError};
+        use chrono::{DateTime, SecondsFormat};
+        use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+        #[derive(Serialize, Deserialize)]
+        struct EpochIso {
+            epoch_millis: Option<i64>,
+            iso: Option<String>,
+        }
+
+        /// Serializes the time as an object with `epoch_millis` and `iso` fields.
+        pub fn serialize<S: Serializer>(time: &Time, serializer: S) -> Result<S::Ok, S::Error> {
+            let datetime = time.signed_utc_datetime().map_err(ser::Error::custom)?;
+            EpochIso {
+                epoch_millis: Some(datetime.timestamp_millis()),
+                iso: Some(datetime.to_rfc3339_opts(SecondsFormat::AutoSi, true)),
+            }
+            .serialize(serializer)
+        }
+
+        /// Deserializes the time from `epoch_millis`, or from `iso` if it is absent.
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Time, D::Error> {
+            let repr = EpochIso::deserialize(deserializer)?;
+            let time = match (repr.epoch_millis, repr.iso) {
+                (Some(millis), _) => Time::from_signed_secs(
+                    millis.div_euclid(1000),
+                    millis.rem_euclid(1000) as u32 * 1_000_000,
+                ),
+                (None, Some(iso)) => DateTime::parse_from_rfc3339(&iso)
+                    .map(|datetime| Time::from(datetime.to_utc()))
+                    .map_err(|e| TimeError::ParseError(e.to_string())),
+                (None, None) => Err(TimeError::ParseError(
+                    "missing both 'epoch_millis' and 'iso'".to_string(),
+                )),
+            };
+            time.map_err(de::Error::custom)
+        }
+    }
 }
\ No newline at end of file
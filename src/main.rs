@@ -29,8 +29,7 @@ fn main() {
     let ist_time = now.to_timezone("+05:30").unwrap();
     println!("Time in IST: {}", ist_time);
 
-    // Make `time_from_str` mutable
-    let mut time_from_str = Time::from_str("2023-10-27 12:00:00+05:30", "%Y-%m-%d %H:%M:%S%z").unwrap();
+    let time_from_str = Time::from_str("2023-10-27 12:00:00+05:30", "%Y-%m-%d %H:%M:%S%z").unwrap();
     println!("Time from string: {}", time_from_str);
 
     // Example usage of CustomDuration struct